@@ -1,18 +1,19 @@
 #![doc = include_str!("../README.md")]
 
-//TODO: no_std will be a project for later
-// #![no_std]
+//NOTE: The macro itself runs on the host, so it's free to use std.  But the code it generates must only
+// depend on `core`, so summum types can be used in `no_std` crates.  See `tests/no_std.rs`
 extern crate alloc;
 use alloc::vec::Vec;
 use alloc::string::String;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 
 use proc_macro::TokenStream;
-use proc_macro2::{TokenTree, Group};
+use proc_macro2::{TokenTree, Group, Span, Spacing};
 use quote::{ToTokens, quote, quote_spanned};
 use heck::{AsUpperCamelCase, AsSnakeCase};
-use syn::parse::{Parse, ParseStream, Result};
-use syn::{parse, parse2, parse::ParseBuffer, parse_quote, parse_macro_input, parse_str, Attribute, Block, Error, Fields, Field, GenericParam, Generics, Ident, ImplItem, ItemEnum, ItemImpl, FnArg, punctuated::Punctuated, Pat, Signature, Token, Type, TypeParam, PathArguments, Variant, Visibility};
+use syn::parse::{Parse, ParseStream, Parser, Result};
+use syn::{parse, parse2, parse::ParseBuffer, parse_quote, parse_macro_input, parse_str, Attribute, Block, Error, Fields, Field, GenericParam, Meta, Generics, Ident, ImplItem, ImplItemFn, ItemEnum, ItemImpl, FnArg, punctuated::Punctuated, Pat, Path, ReturnType, Signature, Token, Type, TypeParam, PathArguments, Variant, Visibility, WhereClause};
 use syn::spanned::Spanned;
 
 struct SummumType {
@@ -23,6 +24,8 @@ struct SummumType {
     cases: Vec<Variant>,
     sub_types: Vec<SubType>,
     struct_fields: Vec<Field>,
+    spreads: Vec<Spread>,
+    options: SummumOptions,
 }
 
 mod keywords {
@@ -32,7 +35,90 @@ mod keywords {
 struct SubType {
     attrs: Vec<Attribute>,
     variant_name: Ident,
-    bindings: Vec<(Ident, Type)>
+    bindings: Vec<(Ident, Type)>,
+    /// The subset of the parent's generics that are still used by the sub-type after the bindings are applied
+    generics: Generics,
+}
+
+/// The options set with a `#[summum(...)]` attribute on the sum-type
+#[derive(Clone, Default)]
+struct SummumOptions {
+    /// The traits to implement by forwarding to the inner value, from `delegate(...)`
+    delegate: Vec<Ident>,
+    /// Implement `Debug` by printing only the inner value, from `transparent_debug`
+    transparent_debug: bool,
+    /// Export the dispatch macro so other crates can use it, from `export_dispatch`
+    export_dispatch: bool,
+    /// Render the `SumTKind` enum and `kind()` method, from `kind`
+    kind: bool,
+    /// Render the `SumTRef` and `SumTMut` borrowed views, from `views`
+    views: bool,
+    /// Render the conversions to and from the other sum-types in the invocation, from `conversions`
+    conversions: bool,
+}
+
+impl SummumOptions {
+    /// Parses and removes the `#[summum(...)]` attributes, so the rest can be passed through to the enum
+    fn take_from_attrs(attrs: &mut Vec<Attribute>) -> Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("summum")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("delegate") {
+                    meta.parse_nested_meta(|trait_meta| {
+                        let trait_ident = &trait_meta.path.segments.last().unwrap().ident;
+                        if !DELEGATE_TRAITS.contains(&trait_ident.to_string().as_str()) {
+                            return Err(trait_meta.error(format!("can't delegate `{}`, expected one of: {}", trait_ident, DELEGATE_TRAITS.join(", "))));
+                        }
+                        //An `Error` must also be `Display`, so delegating `Error` delegates `Display` too
+                        let implied_ident = (trait_ident == "Error").then(|| Ident::new("Display", trait_ident.span()));
+                        for trait_ident in core::iter::once(trait_ident.clone()).chain(implied_ident) {
+                            if !options.delegate.contains(&trait_ident) {
+                                options.delegate.push(trait_ident);
+                            }
+                        }
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("transparent_debug") {
+                    options.transparent_debug = true;
+                    Ok(())
+                } else if meta.path.is_ident("export_dispatch") {
+                    options.export_dispatch = true;
+                    Ok(())
+                } else if meta.path.is_ident("kind") {
+                    options.kind = true;
+                    Ok(())
+                } else if meta.path.is_ident("views") {
+                    options.views = true;
+                    Ok(())
+                } else if meta.path.is_ident("conversions") {
+                    options.conversions = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown summum option, expected `delegate(...)`, `transparent_debug`, `export_dispatch`, `kind`, `views` or `conversions`"))
+                }
+            })?;
+        }
+        attrs.retain(|attr| !attr.path().is_ident("summum"));
+
+        if options.transparent_debug {
+            if let Some(debug_path) = derived_traits(attrs).into_iter().find(|path| path_ends_with(path, "Debug")) {
+                return Err(Error::new(debug_path.span(), "`transparent_debug` implements `Debug`, so it can't also be derived"));
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Another sum-type whose variants are inlined into a Haskell-style sum-type, e.g. `..Scalar`
+struct Spread {
+    type_name: Ident,
+    /// The index in the sum-type's cases where the spread variants are inserted.  Unused for the spreads
+    /// inherited from a spread type, because their variants are inserted along with the spread type's
+    position: usize,
+    /// The spread type's variants, once they've been resolved
+    cases: Option<Vec<Variant>>,
+    /// Whether the spread type has borrowed views, for the `try_as_` and `try_as_mut_` accessors
+    has_views: bool,
 }
 
 impl SubType {
@@ -43,28 +129,36 @@ impl SubType {
 }
 
 impl SummumType {
-    fn parse_haskell_style(input: ParseStream, attrs: Vec<Attribute>, vis: Visibility) -> Result<Self> {
+    fn parse_haskell_style(input: ParseStream, mut attrs: Vec<Attribute>, vis: Visibility) -> Result<Self> {
+        let options = SummumOptions::take_from_attrs(&mut attrs)?;
         let _ = input.parse::<Token![type]>()?;
         let name = input.parse()?;
         let generics: Generics = input.parse()?;
         let _ = input.parse::<Token![=]>()?;
         let mut cases = vec![];
+        let mut spreads = vec![];
 
         loop {
-            let item_type = input.parse()?;
-
-            let item_ident = if input.peek(Token![as]) {
-                let _ = input.parse::<Token![as]>()?;
-                input.parse::<Ident>()?
+            //`..OtherType` inlines the variants of another sum-type, once all the types have been parsed
+            if input.peek(Token![..]) {
+                let _ = input.parse::<Token![..]>()?;
+                let type_name = input.parse()?;
+                spreads.push(Spread{ type_name, position: cases.len(), cases: None, has_views: false });
             } else {
-                ident_from_type_full(&item_type)
-            };
+                let item_type = input.parse()?;
+
+                let item_ident = if input.peek(Token![as]) {
+                    let _ = input.parse::<Token![as]>()?;
+                    input.parse::<Ident>()?
+                } else {
+                    ident_from_type_full(&item_type)
+                };
 
-            let mut variant: Variant = parse(quote!{ #item_ident(#item_type) }.into())?;
-            let sub_type = type_from_fields_mut(&mut variant.fields);
-            canonicalize_type_path(sub_type);
+                let mut variant: Variant = parse(quote!{ #item_ident(#item_type) }.into())?;
+                canonicalize_field_types(&mut variant.fields);
 
-            cases.push(variant);
+                cases.push(variant);
+            }
 
             if input.peek(Token![;]) {
                 let _ = input.parse::<Token![;]>()?;
@@ -82,17 +176,19 @@ impl SummumType {
             cases,
             sub_types: vec![],
             struct_fields: vec![],
+            spreads,
+            options,
         })
     }
 
-    fn parse_enum_style(input: ParseStream, attrs: Vec<Attribute>, vis: Visibility) -> Result<Self> {
+    fn parse_enum_style(input: ParseStream, mut attrs: Vec<Attribute>, vis: Visibility) -> Result<Self> {
+        let options = SummumOptions::take_from_attrs(&mut attrs)?;
         let enum_block: ItemEnum = input.parse()?;
         let name = enum_block.ident;
         let generics = enum_block.generics;
         let cases = enum_block.variants.into_iter()
             .map(|mut variant| {
-                let sub_type = type_from_fields_mut(&mut variant.fields);
-                canonicalize_type_path(sub_type);
+                canonicalize_field_types(&mut variant.fields);
                 variant
             })
             .collect();
@@ -105,10 +201,13 @@ impl SummumType {
             cases,
             sub_types: vec![],
             struct_fields: vec![],
+            spreads: vec![],
+            options,
         })
     }
 
-    fn parse_struct(input: ParseStream, attrs: Vec<Attribute>, vis: Visibility) -> Result<Self> {
+    fn parse_struct(input: ParseStream, mut attrs: Vec<Attribute>, vis: Visibility) -> Result<Self> {
+        let options = SummumOptions::take_from_attrs(&mut attrs)?;
         let _ = input.parse::<Token![struct]>()?;
         let name = input.parse()?;
         let generics: Generics = input.parse()?;
@@ -120,14 +219,24 @@ impl SummumType {
 
         let variants_group_contents: ParseBuffer;
         let _brace_token = syn::braced!(variants_group_contents in input);
-        let sub_types = Self::parse_sub_types_from_struct_variants_clause(variants_group_contents)?;
-        let cases = Self::build_variants_from_sub_types(&name, &generics, &sub_types)?;
+        let mut sub_types = Self::parse_sub_types_from_struct_variants_clause(variants_group_contents)?;
 
         let struct_fields_content: ParseBuffer;
         let _brace_token = syn::braced!(struct_fields_content in input);
-        let struct_fields = struct_fields_content.parse_terminated(Field::parse_named, Token![,])?
+        let struct_fields: Vec<Field> = struct_fields_content.parse_terminated(Field::parse_named, Token![,])?
             .into_iter().collect();
 
+        //Strip the generics that each sub-type no longer uses after its bindings have been remapped,
+        // because rustc rejects unused type params with E0392
+        let (_impl_generics, type_generics, _where_clause) = generics.split_for_impl();
+        let parent_enum_type: Type = parse_quote! { #name #type_generics };
+        for sub_type in sub_types.iter_mut() {
+            let sub_type_fields = remap_sub_type_fields(&struct_fields[..], &sub_type.bindings, &parent_enum_type);
+            sub_type.generics = used_generics(&generics, quote!{ #(#sub_type_fields),* });
+        }
+
+        let cases = Self::build_variants_from_sub_types(&name, &sub_types)?;
+
         Ok(Self {
             attrs,
             vis,
@@ -136,6 +245,8 @@ impl SummumType {
             cases,
             sub_types,
             struct_fields,
+            spreads: vec![],
+            options,
         })
     }
 
@@ -154,7 +265,7 @@ impl SummumType {
             let _paren_token = syn::parenthesized!(bindings_group_contents in input);
             let bindings = Self::parse_bindings_group(bindings_group_contents)?;
 
-            sub_types.push(SubType{attrs, variant_name, bindings});
+            sub_types.push(SubType{attrs, variant_name, bindings, generics: Generics::default()});
 
             //Expect ','
             let _ = input.parse::<Option<Token![,]>>();
@@ -174,17 +285,17 @@ impl SummumType {
         Ok(bindings)
     }
 
-    fn build_variants_from_sub_types(name: &Ident, generics: &Generics, sub_types: &Vec<SubType>) -> Result<Vec<Variant>> {
-        let (_impl_generics, type_generics, _where_clause) = generics.split_for_impl();
+    fn build_variants_from_sub_types(name: &Ident, sub_types: &Vec<SubType>) -> Result<Vec<Variant>> {
         let mut cases = vec![];
 
         for sub_type in sub_types {
+            let (_impl_generics, type_generics, _where_clause) = sub_type.generics.split_for_impl();
             let variant_name = &sub_type.variant_name;
             let sub_type_name = sub_type.struct_type_ident(name);
 
-            let mut variant: Variant = parse(quote!{ #variant_name(#sub_type_name #type_generics) }.into())?;
-            let sub_type = type_from_fields_mut(&mut variant.fields);
-            canonicalize_type_path(sub_type);
+            let cfgs = cfg_attrs(&sub_type.attrs);
+            let mut variant: Variant = parse(quote!{ #(#cfgs)* #variant_name(#sub_type_name #type_generics) }.into())?;
+            canonicalize_field_types(&mut variant.fields);
             cases.push(variant);
         }
 
@@ -202,7 +313,7 @@ impl SummumType {
             SummumType::parse_struct(input, attrs, vis)
         }else {
             input.step(|cursor| {
-                Err(cursor.error(format!("expected `enum`, `struct`, `type`, or `impl`")))
+                Err(cursor.error("expected `enum`, `struct`, `type`, or `impl`"))
             })
         }
     }
@@ -213,7 +324,577 @@ impl SummumType {
         parse_quote! { #name #type_generics }
     }
 
+    /// Renders a definition of the type that can be parsed back by `SummumType::parse`, in order to
+    /// carry the type's definition to other macro invocations
+    fn definition_tokens(&self) -> proc_macro2::TokenStream {
+        let Self {
+            attrs: _,
+            vis,
+            name,
+            generics,
+            cases,
+            sub_types,
+            struct_fields,
+            spreads: _,
+            options: _,
+        } = self;
+        let where_clause = &generics.where_clause;
+
+        if sub_types.is_empty() {
+            quote!{
+                #vis enum #name #generics #where_clause {
+                    #(#cases),*
+                }
+            }
+        } else {
+            let sub_types = sub_types.iter().map(|sub_type| {
+                let SubType { attrs, variant_name, bindings, generics: _ } = sub_type;
+                let bindings = bindings.iter().map(|(key, binding_type)| quote!{ #key = #binding_type });
+                quote!{ #(#attrs)* #variant_name(#(#bindings),*) }
+            });
+            quote!{
+                #vis struct #name #generics #where_clause variants {
+                    #(#sub_types),*
+                } {
+                    #(#struct_fields),*
+                }
+            }
+        }
+    }
+
     fn render(&self) -> TokenStream {
+        let mut new_stream = self.render_definition();
+        new_stream.extend(self.render_impls());
+        if self.options.views {
+            new_stream.extend(self.render_views());
+        }
+        new_stream.extend(self.render_spreads());
+        new_stream.into()
+    }
+
+    /// Creates a borrowed view of this sum-type, e.g. `NumRef<'a>`, where each variant holds references
+    /// to the fields of the corresponding variant of this sum-type
+    fn view_type(&self, view_ident: Ident, mutability: Option<Token![mut]>, doc: String) -> SummumType {
+        let mut generics = self.generics.clone();
+        generics.params.insert(0, parse_quote!{ 'summum_view });
+
+        let cases = self.cases.iter().map(|variant| {
+            let mut view_variant = variant.clone();
+            view_variant.attrs = cfg_attrs(&variant.attrs);
+            view_variant.discriminant = None;
+            for field in view_variant.fields.iter_mut() {
+                let field_type = &field.ty;
+                field.ty = parse_quote!{ &'summum_view #mutability #field_type };
+                field.attrs = vec![];
+            }
+            view_variant
+        }).collect();
+
+        //Any derives that work for the owned sum-type also work for references to its inner values
+        let derives = derived_traits(&self.attrs).into_iter()
+            .filter(|path| VIEW_DERIVES.iter().any(|derive| path_ends_with(path, derive)))
+            .collect::<Vec<_>>();
+        let mut attrs: Vec<Attribute> = vec![parse_quote!{ #[doc = #doc] }];
+        if !derives.is_empty() {
+            attrs.push(parse_quote!{ #[derive(#(#derives),*)] });
+        }
+
+        SummumType {
+            attrs,
+            vis: self.vis.clone(),
+            name: view_ident,
+            generics,
+            cases,
+            sub_types: vec![],
+            struct_fields: vec![],
+            spreads: vec![],
+            //The formatting traits forward to references in the same way, so they work for the views too
+            options: SummumOptions {
+                delegate: self.options.delegate.iter()
+                    .filter(|trait_ident| VIEW_DELEGATE_TRAITS.contains(&trait_ident.to_string().as_str()))
+                    .cloned().collect(),
+                transparent_debug: self.options.transparent_debug,
+                export_dispatch: false,
+                kind: self.options.kind,
+                views: false,
+                conversions: false,
+            },
+        }
+    }
+
+    /// Returns the where clause of `generics`, with an added predicate bounding the type of each field of each
+    /// variant by the trait that `bound` returns for it
+    fn bounded_where_clause(&self, generics: &Generics, bound: &dyn Fn(&Type) -> proc_macro2::TokenStream) -> Option<WhereClause> {
+        //NOTE: `for<'summum>` defers the bounds, which would otherwise be an error for non-generic inner types
+        // that don't implement the trait.  See https://github.com/rust-lang/rust/issues/48214
+        //A where clause can't be conditionally compiled, so the fields of variants with `#[cfg]` attributes are
+        // left out, because their types might not exist
+        let mut bounded_generics = generics.clone();
+        let where_clause = bounded_generics.make_where_clause();
+        let unconditional_cases = self.cases.iter().filter(|variant| cfg_attrs(&variant.attrs).is_empty());
+        for field in unconditional_cases.flat_map(|variant| variant.fields.iter()) {
+            let field_type = &field.ty;
+            let bound = bound(field_type);
+            where_clause.predicates.push(parse_quote!{ #field_type: for<'summum> #bound });
+        }
+        bounded_generics.where_clause
+    }
+
+    /// Returns `true` if one of the variants has an accessor with the given name, e.g. `is_variant` for a
+    /// variant named `Variant`, so a method by that name would conflict with it
+    fn has_variant_accessor(&self, fn_name: &str) -> bool {
+        self.cases.iter().any(|variant| {
+            let ident_string = variant.ident.to_string();
+            ACCESSOR_PREFIXES.iter().any(|prefix| snake_name(prefix, &ident_string) == fn_name)
+        })
+    }
+
+    /// Renders the `SumTRef` and `SumTMut` view types, and the methods to convert between them and the sum-type
+    fn render_views(&self) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let ref_ident = Ident::new(&format!("{name}Ref"), name.span());
+        let mut_ident = Ident::new(&format!("{name}Mut"), name.span());
+        let ref_type = self.view_type(ref_ident.clone(), None, format!("A borrowed view of a [`{name}`], created by [`{name}::as_view`]"));
+        let mut_type = self.view_type(mut_ident.clone(), Some(parse_quote!{ mut }), format!("A mutably borrowed view of a [`{name}`], created by [`{name}::as_mut_view`]"));
+
+        let (impl_generics, _type_generics, where_clause) = self.generics.split_for_impl();
+        let top_enum_type = self.top_enum_type();
+        let (view_impl_generics, _type_generics, _where_clause) = ref_type.generics.split_for_impl();
+        let top_ref_type = ref_type.top_enum_type();
+        let top_mut_type = mut_type.top_enum_type();
+
+        //Arms that convert each variant into the corresponding variant of a view, and back again
+        let view_arms = |view_ident: &Ident| self.cases.iter().map(|variant| {
+            let ident = &variant.ident;
+            let cfgs = cfg_attrs(&variant.attrs);
+            let bindings = field_bindings(&variant.fields);
+            let variant_pattern = variant_with_fields(ident, &variant.fields, &bindings);
+            quote_spanned!{variant.span() =>
+                #(#cfgs)* Self::#variant_pattern => #view_ident::#variant_pattern
+            }
+        }).collect::<Vec<_>>();
+        let ref_arms = view_arms(&ref_ident);
+        let mut_arms = view_arms(&mut_ident);
+        let to_owned_arms = self.cases.iter().map(|variant| {
+            let ident = &variant.ident;
+            let cfgs = cfg_attrs(&variant.attrs);
+            let bindings = field_bindings(&variant.fields);
+            let variant_pattern = variant_with_fields(ident, &variant.fields, &bindings);
+            let cloned_fields = bindings.iter().map(|binding| quote!{ core::clone::Clone::clone(&**#binding) }).collect::<Vec<_>>();
+            let owned_variant = variant_with_fields(ident, &variant.fields, &cloned_fields);
+            quote_spanned!{variant.span() =>
+                #(#cfgs)* Self::#variant_pattern => #name::#owned_variant
+            }
+        }).collect::<Vec<_>>();
+
+        let to_owned_where_clause = self.bounded_where_clause(&ref_type.generics, &|_| quote!{ core::clone::Clone });
+
+        let as_view_doc = format!("Borrows the inner value as a [`{ref_ident}`]");
+        let as_mut_view_doc = format!("Mutably borrows the inner value as a [`{mut_ident}`]");
+        let to_owned_doc = format!("Clones the borrowed inner value into a new [`{name}`]");
+        let ref_definition = ref_type.render_definition();
+        let ref_impls = ref_type.render_view_impls(name);
+        let mut_definition = mut_type.render_definition();
+        let mut_impls = mut_type.render_view_impls(name);
+
+        //A variant named `View` already has accessors by the same names, so they take precedence
+        let as_view_fn = (!self.has_variant_accessor("as_view")).then(|| quote!{
+            #[doc = #as_view_doc]
+            pub fn as_view<'summum_view>(&'summum_view self) -> #top_ref_type {
+                match self{
+                    #(#ref_arms),*
+                }
+            }
+        });
+        let as_mut_view_fn = (!self.has_variant_accessor("as_mut_view")).then(|| quote!{
+            #[doc = #as_mut_view_doc]
+            pub fn as_mut_view<'summum_view>(&'summum_view mut self) -> #top_mut_type {
+                match self{
+                    #(#mut_arms),*
+                }
+            }
+        });
+        quote!{
+            #ref_definition
+            #ref_impls
+            #mut_definition
+            #mut_impls
+
+            impl #view_impl_generics core::clone::Clone for #top_ref_type #where_clause {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl #view_impl_generics core::marker::Copy for #top_ref_type #where_clause {}
+
+            #[allow(dead_code)]
+            impl #impl_generics #top_enum_type #where_clause {
+                #as_view_fn
+                #as_mut_view_fn
+            }
+
+            #[allow(dead_code)]
+            impl #view_impl_generics #top_ref_type #to_owned_where_clause {
+                #[doc = #to_owned_doc]
+                pub fn to_owned_sum(&self) -> #top_enum_type {
+                    match self{
+                        #(#to_owned_arms),*
+                    }
+                }
+            }
+
+            #[allow(dead_code)]
+            impl #view_impl_generics #top_mut_type #to_owned_where_clause {
+                #[doc = #to_owned_doc]
+                pub fn to_owned_sum(&self) -> #top_enum_type {
+                    match self{
+                        #(#to_owned_arms),*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Renders the impls of the traits requested with `#[summum(delegate(...), transparent_debug)]`, which
+    /// forward to the inner value of whichever variant is active
+    fn render_delegations(&self) -> proc_macro2::TokenStream {
+        let (impl_generics, _type_generics, where_clause) = self.generics.split_for_impl();
+        let top_enum_type = self.top_enum_type();
+
+        let bounded_where_clause = |bound: &dyn Fn(&Type) -> proc_macro2::TokenStream| self.bounded_where_clause(&self.generics, bound);
+
+        let mut delegated_impls = vec![];
+        for trait_ident in self.options.delegate.iter() {
+            if let Some(variant) = self.cases.iter().find(|variant| variant.fields.len() != 1) {
+                let message = format!("`delegate({trait_ident})` requires every variant to have exactly one field");
+                return quote_spanned!{variant.span() => compile_error!(#message);};
+            }
+            //Renders methods with the given signatures, whose bodies call the same method of the trait on the
+            // inner value.  Calling through the trait means it doesn't need to be in scope
+            let forward_fns = |trait_path: proc_macro2::TokenStream, sigs: &[proc_macro2::TokenStream]| sigs.iter().map(|sig| {
+                let sig: Signature = parse2(sig.clone()).expect("Error parsing delegated signature");
+                let fn_ident = &sig.ident;
+                let fn_args = sig.inputs.iter().filter_map(ident_for_fn_arg).collect::<Vec<_>>();
+                let arms = self.cases.iter().map(|variant| {
+                    variant_match_arm(quote!{ Self:: }, variant, &quote!{ inner }, quote!{ #trait_path::#fn_ident(inner, #(#fn_args),*) })
+                }).collect::<Vec<_>>();
+                quote!{
+                    #sig {
+                        match self{ #(#arms),* }
+                    }
+                }
+            }).collect::<Vec<_>>();
+
+            //Traits with an associated type, like `Iterator::Item`, need every inner type to agree with the first one
+            let first_type = match self.cases.iter().find(|variant| cfg_attrs(&variant.attrs).is_empty()) {
+                Some(variant) => type_from_fields(&variant.fields),
+                None => {
+                    let message = format!("`delegate({trait_ident})` requires a variant without a `#[cfg]` attribute");
+                    return quote_spanned!{self.name.span() => compile_error!(#message);};
+                }
+            };
+            let first_type_string = first_type.to_token_stream().to_string();
+            //Bounding the first type's associated type by itself would overflow the trait solver
+            let associated_type_bound = |bound_trait: proc_macro2::TokenStream, associated_type: proc_macro2::TokenStream, field_type: &Type| {
+                if field_type.to_token_stream().to_string() == first_type_string {
+                    quote!{ #bound_trait }
+                } else {
+                    quote!{ #bound_trait<#associated_type> }
+                }
+            };
+
+            if trait_ident == "Iterator" {
+                let item_type = quote!{ <#first_type as core::iter::Iterator>::Item };
+                let iterator_bound = |iter_trait: proc_macro2::TokenStream, field_type: &Type| {
+                    associated_type_bound(quote!{ core::iter::#iter_trait }, quote!{ Item = #item_type }, field_type)
+                };
+                let iterator_where_clause = bounded_where_clause(&|field_type| iterator_bound(quote!{ Iterator }, field_type));
+                let double_ended_where_clause = bounded_where_clause(&|field_type| iterator_bound(quote!{ DoubleEndedIterator }, field_type));
+                let exact_size_where_clause = bounded_where_clause(&|field_type| iterator_bound(quote!{ ExactSizeIterator }, field_type));
+                let fused_where_clause = bounded_where_clause(&|field_type| iterator_bound(quote!{ FusedIterator }, field_type));
+
+                let iterator_fns = forward_fns(quote!{ core::iter::Iterator }, &[
+                    quote!{ fn next(&mut self) -> core::option::Option<Self::Item> },
+                    quote!{ fn size_hint(&self) -> (usize, core::option::Option<usize>) },
+                    quote!{ fn nth(&mut self, n: usize) -> core::option::Option<Self::Item> },
+                    quote!{ fn count(self) -> usize },
+                    quote!{ fn last(self) -> core::option::Option<Self::Item> },
+                    quote!{ fn fold<B, F: core::ops::FnMut(B, Self::Item) -> B>(self, init: B, f: F) -> B },
+                ]);
+                let double_ended_fns = forward_fns(quote!{ core::iter::DoubleEndedIterator }, &[
+                    quote!{ fn next_back(&mut self) -> core::option::Option<Self::Item> },
+                    quote!{ fn nth_back(&mut self, n: usize) -> core::option::Option<Self::Item> },
+                    quote!{ fn rfold<B, F: core::ops::FnMut(B, Self::Item) -> B>(self, init: B, f: F) -> B },
+                ]);
+                let exact_size_fns = forward_fns(quote!{ core::iter::ExactSizeIterator }, &[
+                    quote!{ fn len(&self) -> usize },
+                ]);
+                delegated_impls.push(quote!{
+                    impl #impl_generics core::iter::Iterator for #top_enum_type #iterator_where_clause {
+                        type Item = #item_type;
+                        #(#iterator_fns)*
+                    }
+
+                    impl #impl_generics core::iter::DoubleEndedIterator for #top_enum_type #double_ended_where_clause {
+                        #(#double_ended_fns)*
+                    }
+
+                    impl #impl_generics core::iter::ExactSizeIterator for #top_enum_type #exact_size_where_clause {
+                        #(#exact_size_fns)*
+                    }
+
+                    impl #impl_generics core::iter::FusedIterator for #top_enum_type #fused_where_clause {}
+                });
+                continue;
+            }
+
+            if trait_ident == "Future" {
+                //The `Unpin` impl below can't be conditionally compiled, and leaving a variant out of it would be unsound
+                if let Some(variant) = self.cases.iter().find(|variant| !cfg_attrs(&variant.attrs).is_empty()) {
+                    return quote_spanned!{variant.span() => compile_error!("`delegate(Future)` can't be used with `#[cfg]` variants");};
+                }
+                let output_type = quote!{ <#first_type as core::future::Future>::Output };
+                let future_where_clause = bounded_where_clause(&|field_type| {
+                    associated_type_bound(quote!{ core::future::Future }, quote!{ Output = #output_type }, field_type)
+                });
+                let unpin_where_clause = bounded_where_clause(&|_| quote!{ core::marker::Unpin });
+                let poll_arms = self.cases.iter().map(|variant| {
+                    variant_match_arm(quote!{ Self:: }, variant, &quote!{ inner }, quote!{
+                        core::future::Future::poll(unsafe{ core::pin::Pin::new_unchecked(inner) }, cx)
+                    })
+                }).collect::<Vec<_>>();
+
+                //Accessors that project a pinned sum-type onto its pinned inner value
+                let pin_accessors = self.cases.iter().map(|variant| {
+                    let ident = &variant.ident;
+                    let ident_string = ident.to_string();
+                    let sub_type = type_from_fields(&variant.fields);
+                    let try_as_pin_mut_fn_name = Ident::new(&snake_name("try_as_pin_mut", &ident_string), ident.span());
+                    let as_pin_mut_fn_name_str = snake_name("as_pin_mut", &ident_string);
+                    let as_pin_mut_fn_name = Ident::new(&as_pin_mut_fn_name_str, ident.span());
+                    let error_msg = format!("invalid downcast: {}::{{}} expecting {ident_string} found {{}}", self.name);
+                    let arm = variant_match_arm(quote!{ Self:: }, variant, &quote!{ inner }, quote!{
+                        Some(unsafe{ core::pin::Pin::new_unchecked(inner) })
+                    });
+                    let cfgs = cfg_attrs(&variant.attrs);
+                    quote_spanned! {variant.span() =>
+                        #(#cfgs)*
+                        #[allow(dead_code, unreachable_patterns)]
+                        impl #impl_generics #top_enum_type #where_clause {
+                            pub fn #try_as_pin_mut_fn_name<'summum>(self: core::pin::Pin<&'summum mut Self>) -> core::option::Option<core::pin::Pin<&'summum mut #sub_type>> {
+                                //SAFETY: See the `Future` impl
+                                match unsafe{ core::pin::Pin::get_unchecked_mut(self) } {#arm, _=>None}
+                            }
+                            pub fn #as_pin_mut_fn_name<'summum>(self: core::pin::Pin<&'summum mut Self>) -> core::pin::Pin<&'summum mut #sub_type> {
+                                let variant_name = self.variant_name();
+                                self.#try_as_pin_mut_fn_name().unwrap_or_else(|| core::panic!(#error_msg, #as_pin_mut_fn_name_str, variant_name))
+                            }
+                        }
+                    }
+                }).collect::<Vec<_>>();
+
+                delegated_impls.push(quote!{
+                    impl #impl_generics core::future::Future for #top_enum_type #future_where_clause {
+                        type Output = #output_type;
+                        fn poll(self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<Self::Output> {
+                            //SAFETY: The inner value is structurally pinned.  It's never moved out of a pinned sum-type,
+                            // the sum-type is only `Unpin` if all of the inner types are, and it can't implement `Drop`
+                            match unsafe{ core::pin::Pin::get_unchecked_mut(self) } {
+                                #(#poll_arms),*
+                            }
+                        }
+                    }
+
+                    //The sum-type is only `Unpin` if all of its inner types are.  Implementing it explicitly, rather than
+                    // relying on the auto trait, means an `Unpin` impl written by the user is a conflicting impl
+                    impl #impl_generics core::marker::Unpin for #top_enum_type #unpin_where_clause {}
+
+                    #(#pin_accessors)*
+
+                    //A `Drop` impl could move the inner value out of a pinned sum-type, so this conflicts with one
+                    const _: () = {
+                        trait SummumMustNotImplDrop {}
+                        #[allow(drop_bounds)]
+                        impl<T: core::ops::Drop> SummumMustNotImplDrop for T {}
+                        impl #impl_generics SummumMustNotImplDrop for #top_enum_type #where_clause {}
+                    };
+                });
+                continue;
+            }
+
+            //The sum-type is transparent, like its `Display`, so the source is the inner error's source rather
+            // than the inner error itself, which would repeat the same message in the chain
+            if trait_ident == "Error" {
+                let error_where_clause = bounded_where_clause(&|_| quote!{ core::error::Error });
+                let error_fns = forward_fns(quote!{ core::error::Error }, &[
+                    quote!{ fn source(&self) -> core::option::Option<&(dyn core::error::Error + 'static)> },
+                ]);
+                delegated_impls.push(quote!{
+                    impl #impl_generics core::error::Error for #top_enum_type #error_where_clause {
+                        #(#error_fns)*
+                    }
+                });
+                continue;
+            }
+
+            //The `std::io` traits.  Unlike the rest of the generated code, these need `std`
+            let io_fns = match trait_ident.to_string().as_str() {
+                "Read" => Some(forward_fns(quote!{ std::io::Read }, &[
+                    quote!{ fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> },
+                    quote!{ fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> },
+                    quote!{ fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> },
+                    quote!{ fn read_to_string(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> },
+                    quote!{ fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> },
+                ])),
+                "BufRead" => Some(forward_fns(quote!{ std::io::BufRead }, &[
+                    quote!{ fn fill_buf(&mut self) -> std::io::Result<&[u8]> },
+                    quote!{ fn consume(&mut self, amt: usize) },
+                    quote!{ fn read_until(&mut self, byte: u8, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> },
+                    quote!{ fn read_line(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> },
+                    quote!{ fn skip_until(&mut self, byte: u8) -> std::io::Result<usize> },
+                ])),
+                "Write" => Some(forward_fns(quote!{ std::io::Write }, &[
+                    quote!{ fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> },
+                    quote!{ fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> },
+                    quote!{ fn flush(&mut self) -> std::io::Result<()> },
+                    quote!{ fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> },
+                    quote!{ fn write_fmt(&mut self, args: core::fmt::Arguments<'_>) -> std::io::Result<()> },
+                ])),
+                "Seek" => Some(forward_fns(quote!{ std::io::Seek }, &[
+                    quote!{ fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> },
+                    quote!{ fn rewind(&mut self) -> std::io::Result<()> },
+                    quote!{ fn stream_position(&mut self) -> std::io::Result<u64> },
+                    quote!{ fn seek_relative(&mut self, offset: i64) -> std::io::Result<()> },
+                ])),
+                _ => None
+            };
+            if let Some(io_fns) = io_fns {
+                let io_where_clause = bounded_where_clause(&|_| quote!{ std::io::#trait_ident });
+                delegated_impls.push(quote!{
+                    impl #impl_generics std::io::#trait_ident for #top_enum_type #io_where_clause {
+                        #(#io_fns)*
+                    }
+                });
+                continue;
+            }
+
+            let fmt_where_clause = bounded_where_clause(&|_| quote!{ core::fmt::#trait_ident });
+            let fmt_arms = self.cases.iter().map(|variant| {
+                variant_match_arm(quote!{ Self:: }, variant, &quote!{ inner }, quote!{ core::fmt::#trait_ident::fmt(inner, f) })
+            }).collect::<Vec<_>>();
+            delegated_impls.push(quote!{
+                impl #impl_generics core::fmt::#trait_ident for #top_enum_type #fmt_where_clause {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        match self{
+                            #(#fmt_arms),*
+                        }
+                    }
+                }
+            });
+        }
+
+        //Debug prints the inner value on its own, or the variant name for unit variants
+        if self.options.transparent_debug {
+            let debug_where_clause = bounded_where_clause(&|_| quote!{ core::fmt::Debug });
+            let debug_arms = self.cases.iter().map(|variant| {
+                if variant.fields.is_empty() {
+                    let ident = &variant.ident;
+                    let ident_string = ident.to_string();
+                    let cfgs = cfg_attrs(&variant.attrs);
+                    quote_spanned!{variant.span() =>
+                        #(#cfgs)* Self::#ident{..} => f.write_str(#ident_string)
+                    }
+                } else {
+                    variant_match_arm(quote!{ Self:: }, variant, &quote!{ inner }, quote!{ core::fmt::Debug::fmt(&inner, f) })
+                }
+            }).collect::<Vec<_>>();
+            delegated_impls.push(quote!{
+                impl #impl_generics core::fmt::Debug for #top_enum_type #debug_where_clause {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        match self{
+                            #(#debug_arms),*
+                        }
+                    }
+                }
+            });
+        }
+
+        quote!{
+            #(#delegated_impls)*
+        }
+    }
+
+    /// Renders the accessors for the variants spread from other sum-types, e.g. `is_scalar` and `try_into_scalar`
+    /// for `..Scalar`.  The `From` and `TryFrom` impls are covered by the conversions between sum-types
+    fn render_spreads(&self) -> proc_macro2::TokenStream {
+        let (impl_generics, _type_generics, where_clause) = self.generics.split_for_impl();
+        let top_enum_type = self.top_enum_type();
+        let mut view_generics = self.generics.clone();
+        view_generics.params.insert(0, parse_quote!{ 'summum_view });
+        let (_impl_generics, view_type_generics, _where_clause) = view_generics.split_for_impl();
+        let (_impl_generics, type_generics, _where_clause) = self.generics.split_for_impl();
+        let error_ident = try_from_error_ident(&self.name);
+
+        let spread_accessor_impls = self.spreads.iter().map(|spread| {
+            let spread_name = &spread.type_name;
+            let spread_cases = spread.cases.as_deref().unwrap_or_default();
+            let spread_ref = Ident::new(&format!("{spread_name}Ref"), spread_name.span());
+            let spread_mut = Ident::new(&format!("{spread_name}Mut"), spread_name.span());
+
+            let spread_string = spread_name.to_string();
+            let is_fn_name = Ident::new(&snake_name("is", &spread_string), spread_name.span());
+            let try_as_fn_name = Ident::new(&snake_name("try_as", &spread_string), spread_name.span());
+            let try_as_mut_fn_name = Ident::new(&snake_name("try_as_mut", &spread_string), spread_name.span());
+            let try_into_fn_name = Ident::new(&snake_name("try_into", &spread_string), spread_name.span());
+
+            let spread_arms = |wrapper: proc_macro2::TokenStream, result_ident: &Ident| spread_cases.iter().map(|variant| {
+                let cfgs = cfg_attrs(&variant.attrs);
+                let bindings = field_bindings(&variant.fields);
+                let variant_pattern = variant_with_fields(&variant.ident, &variant.fields, &bindings);
+                quote_spanned!{variant.span() =>
+                    #(#cfgs)* Self::#variant_pattern => #wrapper(#result_ident::#variant_pattern)
+                }
+            }).collect::<Vec<_>>();
+            let is_arms = spread_cases.iter().map(|variant| {
+                let ident = &variant.ident;
+                let cfgs = cfg_attrs(&variant.attrs);
+                quote_spanned!{variant.span() =>
+                    #(#cfgs)* Self::#ident{..} => true
+                }
+            }).collect::<Vec<_>>();
+            let ref_arms = spread_arms(quote!{ Some }, &spread_ref);
+            let mut_arms = spread_arms(quote!{ Some }, &spread_mut);
+            let view_fns = spread.has_views.then(|| quote!{
+                pub fn #try_as_fn_name<'summum_view>(&'summum_view self) -> core::option::Option<#spread_ref #view_type_generics> {
+                    match self{#(#ref_arms,)* _=>None}
+                }
+                pub fn #try_as_mut_fn_name<'summum_view>(&'summum_view mut self) -> core::option::Option<#spread_mut #view_type_generics> {
+                    match self{#(#mut_arms,)* _=>None}
+                }
+            });
+            let into_arms = spread_arms(quote!{ Ok }, spread_name);
+            quote!{
+                #[allow(dead_code, unreachable_patterns)]
+                impl #impl_generics #top_enum_type #where_clause {
+                    pub fn #is_fn_name(&self) -> bool {
+                        match self{#(#is_arms,)* _=>false}
+                    }
+                    #view_fns
+                    pub fn #try_into_fn_name(self) -> core::result::Result<#spread_name #type_generics, #error_ident #type_generics> {
+                        match self{#(#into_arms,)* _=>Err(#error_ident::new(self, #spread_string))}
+                    }
+                }
+            }
+        }).collect::<Vec<_>>();
+
+        quote!{
+            #(#spread_accessor_impls)*
+        }
+    }
+
+    /// Renders the enum itself, and the sub-type structs if the sum-type was declared as a struct
+    fn render_definition(&self) -> proc_macro2::TokenStream {
         let Self {
             attrs,
             vis,
@@ -222,39 +903,145 @@ impl SummumType {
             cases,
             sub_types,
             struct_fields,
+            spreads: _,
+            options: _,
         } = self;
 
-        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+        let (_impl_generics, _type_generics, where_clause) = generics.split_for_impl();
         let top_enum_type = self.top_enum_type();
 
-        // render `impl From<VariantT> for SumT`
         let cases_tokens = cases.iter().map(|variant| quote! {
             #variant
         }).collect::<Vec<_>>();
-        let from_impls = cases.iter().map(|variant| {
-            let ident = &variant.ident;
-            let sub_type = type_from_fields(&variant.fields);
 
-            quote_spanned! {variant.span() =>
-                impl #impl_generics From<#sub_type> for #top_enum_type #where_clause {
-                    fn from(val: #sub_type) -> Self {
-                        #name::#ident(val)
-                    }
+        //Render the sub-type structs
+        let sub_types_vec = sub_types.iter().map(|sub_type| {
+            let variant_name = sub_type.struct_type_ident(name);
+            let sub_type_attrs = &sub_type.attrs;
+            let sub_type_fields = remap_sub_type_fields(&struct_fields[..], &sub_type.bindings, &top_enum_type);
+            let (_impl_generics, sub_type_generics, sub_type_where_clause) = sub_type.generics.split_for_impl();
+            quote_spanned! {variant_name.span() =>
+                #(#attrs)*
+                #(#sub_type_attrs)*
+                #vis struct #variant_name #sub_type_generics #sub_type_where_clause {
+                    #(#sub_type_fields),*
                 }
             }
         }).collect::<Vec<_>>();
 
+        quote! {
+            #[allow(dead_code)]
+            #(#attrs)*
+            #vis enum #top_enum_type #where_clause {
+                #(#cases_tokens),*
+            }
+
+            #(#sub_types_vec)*
+        }
+    }
+
+    /// Renders everything that is generated alongside the type definition; the conversions, accessors, etc.
+    fn render_impls(&self) -> proc_macro2::TokenStream {
+        let Self {
+            attrs: _,
+            vis,
+            name,
+            generics,
+            cases,
+            sub_types: _,
+            struct_fields: _,
+            spreads: _,
+            options: _,
+        } = self;
+
+        let (impl_generics, _type_generics, where_clause) = generics.split_for_impl();
+        let top_enum_type = self.top_enum_type();
+
+        let from_impls = self.render_from_impls();
+        let overlapping_types = overlapping_inner_types(cases);
+
+        // render the error type for failed conversions from the sum-type into one of its variants
+        let (_impl_generics, type_generics, _where_clause) = generics.split_for_impl();
+        let error_ident = try_from_error_ident(name);
+        let error_type = quote!{ #error_ident #type_generics };
+        let doc = format!("The error returned when converting a [`{name}`] into the inner type of a variant fails, because it's a different variant");
+        let name_string = name.to_string();
+        //NOTE: `for<'summum>` defers the bound, which would otherwise be an error if the sum-type doesn't
+        // implement the trait.  See https://github.com/rust-lang/rust/issues/48214
+        let mut clone_generics = generics.clone();
+        clone_generics.make_where_clause().predicates.push(parse_quote!{ #top_enum_type: for<'summum> core::clone::Clone });
+        let clone_where_clause = &clone_generics.where_clause;
+        let mut partial_eq_generics = generics.clone();
+        partial_eq_generics.make_where_clause().predicates.push(parse_quote!{ #top_enum_type: for<'summum> core::cmp::PartialEq });
+        let partial_eq_where_clause = &partial_eq_generics.where_clause;
+        let error_type_tokens = quote!{
+            #[doc = #doc]
+            #[allow(dead_code)]
+            #vis struct #error_ident #generics #where_clause {
+                /// The original value, which is returned so it isn't lost
+                pub value: #top_enum_type,
+                /// The name of the variant that was expected, or of the spread sum-type
+                pub expected: &'static str,
+                /// The name of the variant that was found
+                pub found: &'static str,
+            }
+
+            #[allow(dead_code)]
+            impl #impl_generics #error_type #where_clause {
+                /// Creates an error for `value`, which wasn't the `expected` variant
+                pub fn new(value: #top_enum_type, expected: &'static str) -> Self {
+                    let found = value.variant_name();
+                    Self{ value, expected, found }
+                }
+            }
+
+            impl #impl_generics core::fmt::Debug for #error_type #where_clause {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_struct(stringify!(#error_ident))
+                        .field("expected", &self.expected)
+                        .field("found", &self.found)
+                        .finish_non_exhaustive()
+                }
+            }
+
+            impl #impl_generics core::fmt::Display for #error_type #where_clause {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "expected {}::{}, found {}::{}", #name_string, self.expected, #name_string, self.found)
+                }
+            }
+
+            impl #impl_generics core::error::Error for #error_type #where_clause {}
+
+            impl #impl_generics core::clone::Clone for #error_type #clone_where_clause {
+                fn clone(&self) -> Self {
+                    Self{ value: self.value.clone(), expected: self.expected, found: self.found }
+                }
+            }
+
+            impl #impl_generics core::cmp::PartialEq for #error_type #partial_eq_where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    self.value == other.value && self.expected == other.expected
+                }
+            }
+        };
+
         // render `impl TryFrom<SumT> for VariantT`
-        let generic_params = type_params_from_generics(&generics);
+        let generic_params = type_params_from_generics(generics);
         let try_from_impls = cases.iter().map(|variant| {
-            let ident = &variant.ident;
             let sub_type = type_from_fields(&variant.fields);
-            if !detect_uncovered_type(&generic_params[..], &sub_type) {
+            let bindings = field_bindings(&variant.fields);
+            let variant_pattern = variant_with_fields(&variant.ident, &variant.fields, &bindings);
+            let inner_value = inner_value_from_bindings(&bindings);
+            let ident_string = variant.ident.to_string();
+            let cfgs = cfg_attrs(&variant.attrs);
+            if !detect_uncovered_type(&generic_params[..], &sub_type) && !overlapping_types.contains(&sub_type.to_token_stream().to_string()) {
                 quote! {
+                    #(#cfgs)*
+                    #[allow(unreachable_patterns)]
                     impl #impl_generics core::convert::TryFrom<#top_enum_type> for #sub_type #where_clause {
-                        type Error = ();
-                        fn try_from(val: #top_enum_type) -> Result<Self, Self::Error> {
-                            match val{#name::#ident(val)=>Ok(val), _=>Err(())}
+                        type Error = #error_type;
+                        fn try_from(val: #top_enum_type) -> core::result::Result<Self, Self::Error> {
+                            match val{#name::#variant_pattern=>Ok(#inner_value), _=>Err(#error_ident::new(val, #ident_string))}
                         }
                     }
                 }
@@ -266,150 +1053,607 @@ impl SummumType {
         // render `SumT::variants() and SumT::variant_name()`
         let variants_strs = cases.iter().map(|variant| {
             let ident_string = &variant.ident.to_string();
+            let cfgs = cfg_attrs(&variant.attrs);
             quote_spanned! {variant.span() =>
-                #ident_string
-            }
-        }).collect::<Vec<_>>();
-        let variant_name_branches = cases.iter().map(|variant| {
-            let ident = &variant.ident;
-            let ident_string = ident.to_string();
-            quote_spanned! {variant.span() =>
-                Self::#ident(_) => #ident_string
+                #(#cfgs)* #ident_string
             }
         }).collect::<Vec<_>>();
+        let kind_ident = kind_ident(name);
+        let kind_fns_impl = self.render_kind_fns(&kind_ident);
         let variants_impl = quote!{
             #[allow(dead_code)]
             impl #impl_generics #top_enum_type #where_clause {
                 pub const fn variants() -> &'static[&'static str] {
                     &[#(#variants_strs),* ]
                 }
-                pub fn variant_name(&self) -> &'static str {
-                    match self{
-                        #(#variant_name_branches),*
-                    }
-                }
             }
+
+            #kind_fns_impl
         };
 
-        //TODO: I probably don't need the guide object, because I don't know how I get execute its functions within the macro, and I don't have the stamina for macro-layering insanity
-        // let mut guide_name_string = name.to_string();
-        // guide_name_string.push_str("Guide");
-        // let guide_name = Ident::new(&guide_name_string, name.span());
-        // let guide_type = quote!{
-        //     struct #guide_name;
+        // render the fieldless `SumTKind` enum, to identify variants without their inner values, if it was asked for
+        let doc = format!("The variants of [`{name}`], without their inner values");
+        let kind_variants = cases.iter().map(|variant| {
+            let ident = &variant.ident;
+            let cfgs = cfg_attrs(&variant.attrs);
+            let doc = format!("The kind of the [`{name}::{ident}`] variant");
+            quote_spanned! {variant.span() =>
+                #(#cfgs)*
+                #[doc = #doc]
+                #ident
+            }
+        }).collect::<Vec<_>>();
+        let kind_all = cases.iter().map(|variant| {
+            let ident = &variant.ident;
+            let cfgs = cfg_attrs(&variant.attrs);
+            quote_spanned! {variant.span() =>
+                #(#cfgs)* Self::#ident
+            }
+        }).collect::<Vec<_>>();
+        let kind_from_str_branches = cases.iter().map(|variant| {
+            let ident = &variant.ident;
+            let ident_string = ident.to_string();
+            let cfgs = cfg_attrs(&variant.attrs);
+            quote_spanned! {variant.span() =>
+                #(#cfgs)* #ident_string => Ok(Self::#ident)
+            }
+        }).collect::<Vec<_>>();
+        let variant_name_branches = self.variant_name_branches();
+        let kind_parse_error_ident = kind_parse_error_ident(name);
+        let kind_parse_error_doc = format!("The error returned when a string doesn't name a variant of [`{name}`]");
+        let kind_parse_error_message = format!("unknown variant of {name}");
+        let kind_tokens = if self.options.kind {
+            quote!{
+                #[doc = #doc]
+                #[allow(dead_code)]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+                #vis enum #kind_ident {
+                    #(#kind_variants),*
+                }
 
-        //     impl #guide_name {
-        //         pub const fn variants() -> &'static[&'static str] {
-        //             &[#(#variants_strs),* ]
-        //         }
-        //     }
-        // };
+                #[allow(dead_code)]
+                impl #kind_ident {
+                    /// Every kind, in the order the variants are declared
+                    pub const ALL: &'static [Self] = &[#(#kind_all),* ];
+
+                    /// The name of the variant
+                    pub const fn name(self) -> &'static str {
+                        match self{
+                            #(#variant_name_branches),*
+                        }
+                    }
+                }
+
+                impl core::str::FromStr for #kind_ident {
+                    type Err = #kind_parse_error_ident;
+                    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+                        match s{
+                            #(#kind_from_str_branches,)*
+                            _ => Err(#kind_parse_error_ident)
+                        }
+                    }
+                }
+
+                #[doc = #kind_parse_error_doc]
+                #[allow(dead_code)]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                #vis struct #kind_parse_error_ident;
+
+                impl core::fmt::Display for #kind_parse_error_ident {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        f.write_str(#kind_parse_error_message)
+                    }
+                }
+
+                impl core::error::Error for #kind_parse_error_ident {}
+            }
+        } else {
+            quote!{}
+        };
 
         // render individual variant accessor methods
-        let accessor_impls = cases.iter().map(|variant| {
+        let accessor_impls = self.render_accessors(Some((&error_ident, &error_type)));
+
+        // render the variant marker types, and the generic accessors that take them
+        let variants_mod = variants_mod_ident(name);
+        let doc = format!("Zero-sized marker types for each of the variants of [`{name}`], for use with the generic accessors");
+        let markers = cases.iter().map(|variant| {
             let ident = &variant.ident;
-            let sub_type = type_from_fields(&variant.fields);
+            let doc = format!("Marker for the [`{name}::{ident}`](super::{name}::{ident}) variant");
+            let cfgs = cfg_attrs(&variant.attrs);
+            quote_spanned! {variant.span() =>
+                #(#cfgs)*
+                #[doc = #doc]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+                pub struct #ident;
+            }
+        }).collect::<Vec<_>>();
+        let markers_mod = quote!{
+            #[doc = #doc]
+            #[allow(dead_code)]
+            #vis mod #variants_mod {
+                /// The trait implemented by the marker types.  It lives in its own module, so it can't conflict
+                /// with the marker for a variant named `Variant`
+                pub mod traits {
+                    /// Implemented by each marker type, to access the corresponding variant of the sum-type `SumT`
+                    pub trait Variant<SumT> {
+                        /// The inner type of the variant
+                        type Inner;
+                        /// A reference to the inner value, or a tuple of references if the variant has several fields
+                        type Ref<'summum> where SumT: 'summum;
+                        /// A mutable reference to the inner value, or a tuple of mutable references if the variant has several fields
+                        type Mut<'summum> where SumT: 'summum;
+                        /// The name of the variant
+                        const NAME: &'static str;
+                        /// Returns a reference to the inner value, if `sum` is this variant
+                        fn try_as<'summum>(sum: &'summum SumT) -> core::option::Option<Self::Ref<'summum>>;
+                        /// Returns a mutable reference to the inner value, if `sum` is this variant
+                        fn try_as_mut<'summum>(sum: &'summum mut SumT) -> core::option::Option<Self::Mut<'summum>>;
+                        /// Returns the inner value if `sum` is this variant, otherwise returns `sum`
+                        fn try_into(sum: SumT) -> core::result::Result<Self::Inner, SumT>;
+                    }
+                }
 
+                #(#markers)*
+            }
+        };
+        let marker_impls = cases.iter().map(|variant| {
+            let ident = &variant.ident;
             let ident_string = ident.to_string();
-            let is_fn_name = Ident::new(&snake_name("is", &ident_string), variant.ident.span());
-            let try_as_fn_name = Ident::new(&snake_name("try_as", &ident_string), variant.ident.span());
-            let as_fn_name_str = snake_name("as", &ident_string);
-            let as_fn_name = Ident::new(&as_fn_name_str, variant.ident.span());
-            let try_as_mut_fn_name = Ident::new(&snake_name("try_as_mut", &ident_string), variant.ident.span());
-            let as_mut_fn_name_str = snake_name("as_mut", &ident_string);
-            let as_mut_fn_name = Ident::new(&as_mut_fn_name_str, variant.ident.span());
-            let try_into_fn_name = Ident::new(&snake_name("try_into", &ident_string), variant.ident.span());
-            let into_fn_name_str = snake_name("into", &ident_string);
-            let into_fn_name = Ident::new(&into_fn_name_str, variant.ident.span());
-
-            let error_msg = format!("invalid downcast: {name}::{{}} expecting {ident_string} found {{}}");
+            let sub_type = type_from_fields(&variant.fields);
+            let bindings = field_bindings(&variant.fields);
+            let variant_pattern = variant_with_fields(ident, &variant.fields, &bindings);
+            let inner_value = inner_value_from_bindings(&bindings);
+            let ref_type = ref_type_from_fields(&variant.fields, quote!{ &'summum });
+            let mut_type = ref_type_from_fields(&variant.fields, quote!{ &'summum mut });
+            let cfgs = cfg_attrs(&variant.attrs);
             quote_spanned! {variant.span() =>
-                pub fn #is_fn_name(&self) -> bool {
-                    match self{Self::#ident(_)=>true, _=>false}
-                }
-                pub fn #try_as_fn_name(&self) -> Option<&#sub_type> {
-                    match self{Self::#ident(val)=>Some(val), _=>None}
-                }
-                pub fn #as_fn_name(&self) -> &#sub_type {
-                    self.#try_as_fn_name().unwrap_or_else(|| panic!(#error_msg, #as_fn_name_str, self.variant_name()))
+                #(#cfgs)*
+                #[allow(unreachable_patterns)]
+                impl #impl_generics #variants_mod::traits::Variant<#top_enum_type> for #variants_mod::#ident #where_clause {
+                    type Inner = #sub_type;
+                    type Ref<'summum> = #ref_type where #top_enum_type: 'summum;
+                    type Mut<'summum> = #mut_type where #top_enum_type: 'summum;
+                    const NAME: &'static str = #ident_string;
+                    fn try_as<'summum>(sum: &'summum #top_enum_type) -> core::option::Option<#ref_type> {
+                        match sum{#name::#variant_pattern=>Some(#inner_value), _=>None}
+                    }
+                    fn try_as_mut<'summum>(sum: &'summum mut #top_enum_type) -> core::option::Option<#mut_type> {
+                        match sum{#name::#variant_pattern=>Some(#inner_value), _=>None}
+                    }
+                    fn try_into(sum: #top_enum_type) -> core::result::Result<#sub_type, #top_enum_type> {
+                        match sum{#name::#variant_pattern=>Ok(#inner_value), _=>Err(sum)}
+                    }
                 }
-                pub fn #try_as_mut_fn_name(&mut self) -> Option<&mut #sub_type> {
-                    match self{Self::#ident(val)=>Some(val), _=>None}
+            }
+        }).collect::<Vec<_>>();
+        //The generic accessors are suffixed with `_variant` so they don't shadow traits like `TryInto`.  A variant
+        // named `Variant` already has accessors by the same names, so those take precedence
+        let generic_accessors = [
+            ("is_variant", quote!{
+                pub fn is_variant<M: #variants_mod::traits::Variant<Self>>(&self) -> bool {
+                    M::try_as(self).is_some()
                 }
-                pub fn #as_mut_fn_name(&mut self) -> &mut #sub_type {
-                    let variant_name = self.variant_name();
-                    self.#try_as_mut_fn_name().unwrap_or_else(|| panic!(#error_msg, #as_mut_fn_name_str, variant_name))
+            }),
+            ("try_as_variant", quote!{
+                pub fn try_as_variant<M: #variants_mod::traits::Variant<Self>>(&self) -> core::option::Option<M::Ref<'_>> {
+                    M::try_as(self)
                 }
-                pub fn #try_into_fn_name(self) -> core::result::Result<#sub_type, Self> {
-                    match self{Self::#ident(val)=>Ok(val), _=>Err(self)}
+            }),
+            ("try_as_mut_variant", quote!{
+                pub fn try_as_mut_variant<M: #variants_mod::traits::Variant<Self>>(&mut self) -> core::option::Option<M::Mut<'_>> {
+                    M::try_as_mut(self)
                 }
-                pub fn #into_fn_name(self) -> #sub_type {
-                    self.#try_into_fn_name().unwrap_or_else(|t| panic!(#error_msg, #into_fn_name_str, t.variant_name()))
+            }),
+            ("try_into_variant", quote!{
+                pub fn try_into_variant<M: #variants_mod::traits::Variant<Self>>(self) -> core::result::Result<M::Inner, #error_type> {
+                    M::try_into(self).map_err(|value| #error_ident::new(value, M::NAME))
                 }
-            }
-        }).collect::<Vec<_>>();
-        let accessors_impl = quote!{
+            }),
+        ];
+        let generic_accessor_fns = generic_accessors.into_iter()
+            .filter(|(fn_name, _)| !self.has_variant_accessor(fn_name))
+            .map(|(_, fn_tokens)| fn_tokens);
+        let generic_accessors_impl = quote!{
             #[allow(dead_code)]
             impl #impl_generics #top_enum_type #where_clause {
-                #(#accessor_impls)*
+                #(#generic_accessor_fns)*
             }
         };
 
-        // //TODO: re-enable this feature when https://github.com/rust-lang/rust/issues/8995 is available in stable
-        // let variant_type_aliases = cases.iter().map(|variant| {
-        //     let ident = &variant.ident;
-        //     let variant_type_ident = Ident::new(&format!("{}T", ident.to_string()), ident.span());
-        //     let sub_type = type_from_fields(&variant.fields);
-
-        //     quote_spanned! {variant.span() =>
-        //         pub type #variant_type_ident = #sub_type;
-        //     }
-        // }).collect::<Vec<_>>();
-        // let variant_type_aliases_impl = quote!{
-        //     #[allow(dead_code)]
-        //     impl #impl_generics #top_enum_type #where_clause {
-        //         #(#variant_type_aliases)*
-        //     }
-        // };
+        // render `type SumTVariantT = VariantT` aliases, so code outside the summum block can name the inner types.
+        //NOTE: These would ideally be associated types, but https://github.com/rust-lang/rust/issues/8995 isn't stable
+        let variant_type_aliases = cases.iter().map(|variant| {
+            let ident = &variant.ident;
+            let alias_ident = variant_type_alias_ident(name, ident);
+            let sub_type = type_from_fields(&variant.fields);
+            let alias_generics = bare_generic_params(&used_generics(generics, sub_type.to_token_stream()));
+            let doc = format!("The inner type of the [`{name}::{ident}`] variant");
+            let cfgs = cfg_attrs(&variant.attrs);
 
-        //Render the sub-type structs
-        let sub_types_vec = sub_types.iter().map(|sub_type| {
-            let variant_name = sub_type.struct_type_ident(name);
-            let sub_type_attrs = &sub_type.attrs;
-            let sub_type_fields = remap_sub_type_fields(&struct_fields[..], &sub_type.bindings, &top_enum_type);
-            quote_spanned! {variant_name.span() =>
-                #(#attrs)*
-                #(#sub_type_attrs)*
-                #vis struct #variant_name #type_generics #where_clause {
-                    #(#sub_type_fields),*
-                }
+            quote_spanned! {variant.span() =>
+                #(#cfgs)*
+                #[doc = #doc]
+                #[allow(dead_code)]
+                #vis type #alias_ident #alias_generics = #sub_type;
             }
         }).collect::<Vec<_>>();
 
+        //Render the callback macro that carries the type definition to `summum_impl!` invocations elsewhere
+        let callback_macro = callback_macro_ident(name);
+        let definition = self.definition_tokens();
+        let callback_macro_tokens = quote!{
+            #[doc(hidden)]
+            #[allow(unused_macros)]
+            macro_rules! #callback_macro {
+                ($($impl_tokens:tt)*) => {
+                    ::summum_types::__summum_impl!{ #definition $($impl_tokens)* }
+                }
+            }
+            #[doc(hidden)]
+            #[allow(unused_imports)]
+            pub(crate) use #callback_macro;
+        };
+
+        //Render the dispatch macro, so code outside the impl blocks can also be generic over the variants
+        let dispatch_macro = dispatch_macro_ident(name);
+        let dispatch_macro_tokens = if self.options.export_dispatch {
+            if !matches!(vis, Visibility::Public(_)) {
+                return quote_spanned!{name.span() => compile_error!("`export_dispatch` requires a `pub` sum-type");};
+            }
+            //`#[macro_export]` puts the macro at the crate root, so it gets a name that's unique to the definition,
+            // and is re-exported next to the sum-type under its usual name
+            let mut hasher = std::hash::DefaultHasher::new();
+            definition.to_string().hash(&mut hasher);
+            let exported_macro = Ident::new(&format!("__summum_dispatch_{name}_{:x}", hasher.finish()), name.span());
+            quote!{
+                #[doc(hidden)]
+                #[macro_export]
+                macro_rules! #exported_macro {
+                    ($sum:expr, |$inner:pat_param| $($body:tt)*) => {
+                        ::summum_types::__summum_dispatch!{ { #definition } ($sum) ($inner) $($body)* }
+                    }
+                }
+                #[doc(inline)]
+                #[allow(unused_imports)]
+                pub use #exported_macro as #dispatch_macro;
+            }
+        } else {
+            quote!{
+                #[allow(unused_macros)]
+                macro_rules! #dispatch_macro {
+                    ($sum:expr, |$inner:pat_param| $($body:tt)*) => {
+                        ::summum_types::__summum_dispatch!{ { #definition } ($sum) ($inner) $($body)* }
+                    }
+                }
+                #[allow(unused_imports)]
+                pub(crate) use #dispatch_macro;
+            }
+        };
+
+        let delegated_impls = self.render_delegations();
+
         //Top-level renderer that produces the output
         quote! {
+            #from_impls
+
+            #error_type_tokens
+
+            #(#try_from_impls)*
+
+            #variants_impl
+
+            #kind_tokens
+
+            #(#accessor_impls)*
+
+            #markers_mod
+
+            #(#marker_impls)*
+
+            #generic_accessors_impl
+
+            #(#variant_type_aliases)*
+
+            #callback_macro_tokens
+
+            #dispatch_macro_tokens
+
+            #delegated_impls
+        }
+    }
+
+    /// Renders `impl From<VariantT> for SumT` for each variant
+    fn render_from_impls(&self) -> proc_macro2::TokenStream {
+        let (impl_generics, _type_generics, where_clause) = self.generics.split_for_impl();
+        let top_enum_type = self.top_enum_type();
+        let name = &self.name;
+
+        //If multiple variants share an inner type, the From and TryFrom impls would conflict, so we
+        // leave them out for that type, and rely on the `from_*` constructors instead
+        let overlapping_types = overlapping_inner_types(&self.cases);
+        let from_impls = self.cases.iter().map(|variant| {
+            let sub_type = type_from_fields(&variant.fields);
+            if overlapping_types.contains(&sub_type.to_token_stream().to_string()) {
+                return quote!{};
+            }
+            let val = Ident::new("val", Span::call_site());
+            let construct_variant = variant_with_fields(&variant.ident, &variant.fields, &fields_from_inner_value(&variant.fields, &val));
+            let val_pat = inner_value_arg_pat(&variant.fields, &val);
+            let cfgs = cfg_attrs(&variant.attrs);
+
+            quote_spanned! {variant.span() =>
+                #(#cfgs)*
+                impl #impl_generics core::convert::From<#sub_type> for #top_enum_type #where_clause {
+                    fn from(#val_pat: #sub_type) -> Self {
+                        #name::#construct_variant
+                    }
+                }
+            }
+        }).collect::<Vec<_>>();
+
+        quote!{
+            #(#from_impls)*
+        }
+    }
+
+    /// The match arms that map each variant to its name
+    fn variant_name_branches(&self) -> Vec<proc_macro2::TokenStream> {
+        self.cases.iter().map(|variant| {
+            let ident = &variant.ident;
+            let ident_string = ident.to_string();
+            let cfgs = cfg_attrs(&variant.attrs);
+            quote_spanned! {variant.span() =>
+                #(#cfgs)* Self::#ident{..} => #ident_string
+            }
+        }).collect()
+    }
+
+    /// Renders `SumT::variant_name()`, and `SumT::kind()` returning a variant of `kind_ident` if the `kind` option
+    /// is set
+    fn render_kind_fns(&self, kind_ident: &Ident) -> proc_macro2::TokenStream {
+        let (impl_generics, _type_generics, where_clause) = self.generics.split_for_impl();
+        let top_enum_type = self.top_enum_type();
+        let variant_name_branches = self.variant_name_branches();
+        let kind_branches = self.cases.iter().map(|variant| {
+            let ident = &variant.ident;
+            let cfgs = cfg_attrs(&variant.attrs);
+            quote_spanned! {variant.span() =>
+                #(#cfgs)* Self::#ident{..} => #kind_ident::#ident
+            }
+        }).collect::<Vec<_>>();
+        let kind_fn = if self.options.kind {
+            quote!{
+                pub fn kind(&self) -> #kind_ident {
+                    match self{
+                        #(#kind_branches),*
+                    }
+                }
+            }
+        } else {
+            quote!{}
+        };
+        quote!{
             #[allow(dead_code)]
-            #(#attrs)*
-            #vis enum #top_enum_type #where_clause {
-                #(#cases_tokens),*
+            impl #impl_generics #top_enum_type #where_clause {
+                pub fn variant_name(&self) -> &'static str {
+                    match self{
+                        #(#variant_name_branches),*
+                    }
+                }
+                #kind_fn
+            }
+        }
+    }
+
+    /// Renders the accessor methods for each variant, e.g. `is_f64` and `try_as_f64`.  `try_into_` methods fail
+    /// with the given error type, or by returning the sum-type itself if there isn't one
+    fn render_accessors(&self, try_into_error: Option<(&Ident, &proc_macro2::TokenStream)>) -> Vec<proc_macro2::TokenStream> {
+        let (impl_generics, _type_generics, where_clause) = self.generics.split_for_impl();
+        let top_enum_type = self.top_enum_type();
+        let name = &self.name;
+        self.cases.iter().map(|variant| {
+            let ident = &variant.ident;
+            let sub_type = type_from_fields(&variant.fields);
+
+            let ident_string = ident.to_string();
+            let is_fn_name = Ident::new(&snake_name("is", &ident_string), variant.ident.span());
+            let try_as_fn_name = Ident::new(&snake_name("try_as", &ident_string), variant.ident.span());
+            let as_fn_name_str = snake_name("as", &ident_string);
+            let as_fn_name = Ident::new(&as_fn_name_str, variant.ident.span());
+            let try_as_mut_fn_name = Ident::new(&snake_name("try_as_mut", &ident_string), variant.ident.span());
+            let as_mut_fn_name_str = snake_name("as_mut", &ident_string);
+            let as_mut_fn_name = Ident::new(&as_mut_fn_name_str, variant.ident.span());
+            let try_into_fn_name = Ident::new(&snake_name("try_into", &ident_string), variant.ident.span());
+            let into_fn_name_str = snake_name("into", &ident_string);
+            let into_fn_name = Ident::new(&into_fn_name_str, variant.ident.span());
+            let from_fn_name = Ident::new(&snake_name("from", &ident_string), variant.ident.span());
+
+            //Variants with several fields are accessed as a tuple, or a tuple of references
+            let val = Ident::new("val", Span::call_site());
+            let construct_variant = variant_with_fields(ident, &variant.fields, &fields_from_inner_value(&variant.fields, &val));
+            let val_pat = inner_value_arg_pat(&variant.fields, &val);
+            let bindings = field_bindings(&variant.fields);
+            let variant_pattern = variant_with_fields(ident, &variant.fields, &bindings);
+            let inner_value = inner_value_from_bindings(&bindings);
+            let ref_type = ref_type_from_fields(&variant.fields, quote!{ & });
+            let mut_type = ref_type_from_fields(&variant.fields, quote!{ &mut });
+
+            let error_msg = format!("invalid downcast: {name}::{{}} expecting {ident_string} found {{}}");
+            let (error_type, error_value, found_name) = match try_into_error {
+                Some((error_ident, error_type)) => (error_type.clone(), quote!{ #error_ident::new(self, #ident_string) }, quote!{ err.found }),
+                None => (quote!{ Self }, quote!{ self }, quote!{ err.variant_name() }),
+            };
+            let cfgs = cfg_attrs(&variant.attrs);
+            quote_spanned! {variant.span() =>
+                #(#cfgs)*
+                #[allow(dead_code, unreachable_patterns)]
+                impl #impl_generics #top_enum_type #where_clause {
+                    pub fn #from_fn_name(#val_pat: #sub_type) -> Self {
+                        Self::#construct_variant
+                    }
+                    pub fn #is_fn_name(&self) -> bool {
+                        match self{Self::#ident{..}=>true, _=>false}
+                    }
+                    pub fn #try_as_fn_name(&self) -> core::option::Option<#ref_type> {
+                        match self{Self::#variant_pattern=>Some(#inner_value), _=>None}
+                    }
+                    pub fn #as_fn_name(&self) -> #ref_type {
+                        self.#try_as_fn_name().unwrap_or_else(|| core::panic!(#error_msg, #as_fn_name_str, self.variant_name()))
+                    }
+                    pub fn #try_as_mut_fn_name(&mut self) -> core::option::Option<#mut_type> {
+                        match self{Self::#variant_pattern=>Some(#inner_value), _=>None}
+                    }
+                    pub fn #as_mut_fn_name(&mut self) -> #mut_type {
+                        let variant_name = self.variant_name();
+                        self.#try_as_mut_fn_name().unwrap_or_else(|| core::panic!(#error_msg, #as_mut_fn_name_str, variant_name))
+                    }
+                    pub fn #try_into_fn_name(self) -> core::result::Result<#sub_type, #error_type> {
+                        match self{Self::#variant_pattern=>Ok(#inner_value), _=>Err(#error_value)}
+                    }
+                    pub fn #into_fn_name(self) -> #sub_type {
+                        self.#try_into_fn_name().unwrap_or_else(|err| core::panic!(#error_msg, #into_fn_name_str, #found_name))
+                    }
+                }
             }
+        }).collect()
+    }
 
-            #(#sub_types_vec)*
+    /// Renders the impls for a `SumTRef` or `SumTMut` view of the `owner_name` sum-type.  The view shares its
+    /// kind enum, error types, macros, etc. with the sum-type, so only the conversions, accessors and delegated
+    /// traits are rendered for it
+    fn render_view_impls(&self, owner_name: &Ident) -> proc_macro2::TokenStream {
+        let from_impls = self.render_from_impls();
+        let kind_fns_impl = self.render_kind_fns(&kind_ident(owner_name));
+        let accessor_impls = self.render_accessors(None);
+        let delegated_impls = self.render_delegations();
+        quote!{
+            #from_impls
 
-            #(#from_impls)*
+            #kind_fns_impl
 
-            #(#try_from_impls)*
+            #(#accessor_impls)*
 
-            #variants_impl
+            #delegated_impls
+        }
+    }
 
-            #accessors_impl
+    /// Renders a match statement on `sum_expr`, binding `inner_pat` to the inner value and then expanding
+    /// `body` separately for each variant, in the same way as a method body in an impl block
+    fn render_dispatch(&self, types: &HashMap<String, SummumType>, sum_expr: proc_macro2::TokenStream, inner_pat: proc_macro2::TokenStream, body: proc_macro2::TokenStream) -> TokenStream {
+        let name = &self.name;
+
+        let mut match_arms = vec![];
+        for variant in self.cases.iter() {
+            let ident = &variant.ident;
+            let ident_string = ident.to_string();
+            let variant_t_name = format!("{}T", ident_string);
+            let sub_type = type_from_fields(&variant.fields);
+            let sub_type_string = quote!{ #sub_type }.to_string();
+
+            let block_tokenstream = replace_idents(replace_variant_type_paths(quote!{ { #body } }, types, &ident_string), &[
+                ("VariantT", &variant_t_name),
+                ("InnerT", &sub_type_string),
+            ], &[
+                ("_inner_var", &|base| snake_name(base, &ident_string))
+            ]);
+            let block_tokenstream = match handle_inner_macros(block_tokenstream, &ident_string) {
+                Ok(block_tokenstream) => block_tokenstream,
+                Err(err) => {return err.into();}
+            };
 
-            //TODO.  see above
-            // #variant_type_aliases_impl
+            //The binding is unused in the arms where `summum_restrict!` or `summum_exclude!` panic
+            let match_arm = variant_match_arm(quote!{ #name:: }, variant, &inner_pat, block_tokenstream);
+            match_arms.push(quote!{ #[allow(unused_variables)] #match_arm });
+        }
 
-            // #guide_type
+        quote!{
+            match #sum_expr {
+                #(#match_arms),*
+            }
         }.into()
     }
+
+    /// Renders `From<Self> for Big` and `TryFrom<Big> for Self`, if every variant of this sum-type also
+    /// appears in `big` with the same name and inner type, and either type has the `conversions` option or `big`
+    /// spreads this type.  Renders nothing otherwise
+    fn render_conversions(&self, big: &SummumType) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let big_name = &big.name;
+        if name == big_name || self.generics.to_token_stream().to_string() != big.generics.to_token_stream().to_string() {
+            return quote!{};
+        }
+        let is_spread = big.spreads.iter().any(|spread| &spread.type_name == name);
+        if !self.options.conversions && !big.options.conversions && !is_spread {
+            return quote!{};
+        }
+        if !self.cases.iter().all(|variant| big.cases.iter().any(|big_variant| big_variant.ident == variant.ident && variant_shape(big_variant) == variant_shape(variant))) {
+            return quote!{};
+        }
+
+        //If either type is an inner type of the other, or a variant's inner type is a bare generic, then
+        // the From and TryFrom impls generated for the inner types would conflict with these
+        let (impl_generics, _type_generics, where_clause) = self.generics.split_for_impl();
+        let top_enum_type = self.top_enum_type();
+        let big_enum_type = big.top_enum_type();
+        let generic_params = type_params_from_generics(&self.generics);
+        let is_inner_type_of = |outer: &SummumType, inner_type: &Type| {
+            let inner_type_string = inner_type.to_token_stream().to_string();
+            outer.cases.iter().any(|variant| type_from_fields(&variant.fields).to_token_stream().to_string() == inner_type_string)
+        };
+        let has_uncovered_type = |sum_type: &SummumType| {
+            sum_type.cases.iter().any(|variant| detect_uncovered_type(&generic_params[..], &type_from_fields(&variant.fields)))
+        };
+        if is_inner_type_of(big, &top_enum_type) || is_inner_type_of(self, &big_enum_type) || has_uncovered_type(self) || has_uncovered_type(big) {
+            return quote!{};
+        }
+
+        let widen_arms = self.cases.iter().map(|variant| {
+            let bindings = field_bindings(&variant.fields);
+            let variant_pattern = variant_with_fields(&variant.ident, &variant.fields, &bindings);
+            let cfgs = cfg_attrs(&variant.attrs);
+            quote_spanned! {variant.span() =>
+                #(#cfgs)* #name::#variant_pattern => #big_name::#variant_pattern
+            }
+        }).collect::<Vec<_>>();
+        let widen_impl = quote!{
+            impl #impl_generics core::convert::From<#top_enum_type> for #big_enum_type #where_clause {
+                fn from(val: #top_enum_type) -> Self {
+                    match val{
+                        #(#widen_arms),*
+                    }
+                }
+            }
+        };
+
+        //If the two types have exactly the same variants, there are `From` impls in both directions, and
+        // the blanket `TryFrom` impl covers narrowing
+        if self.cases.len() == big.cases.len() {
+            return widen_impl;
+        }
+        let narrow_arms = self.cases.iter().map(|variant| {
+            let bindings = field_bindings(&variant.fields);
+            let variant_pattern = variant_with_fields(&variant.ident, &variant.fields, &bindings);
+            let cfgs = cfg_attrs(&variant.attrs);
+            quote_spanned! {variant.span() =>
+                #(#cfgs)* #big_name::#variant_pattern => Ok(#name::#variant_pattern)
+            }
+        }).collect::<Vec<_>>();
+        quote!{
+            #widen_impl
+
+            #[allow(unreachable_patterns)]
+            impl #impl_generics core::convert::TryFrom<#big_enum_type> for #top_enum_type #where_clause {
+                type Error = #big_enum_type;
+                fn try_from(val: #big_enum_type) -> core::result::Result<Self, Self::Error> {
+                    match val{
+                        #(#narrow_arms,)*
+                        _ => Err(val)
+                    }
+                }
+            }
+        }
+    }
 }
 
 struct SummumImpl {
@@ -420,13 +1664,8 @@ struct SummumImpl {
 impl SummumImpl {
     fn parse(input: ParseStream, attrs: Vec<Attribute>) -> Result<Self> {
         let mut item_impl: ItemImpl = input.parse()?;
-
-        if item_impl.trait_.is_some() {
-            return Err(Error::new(item_impl.span(), format!("impl for traits doesn't belong in summum block")));
-        }
-
         item_impl.attrs = attrs;
-        let item_type_name = ident_from_type_short(&*item_impl.self_ty)?;
+        let item_type_name = ident_from_type_short(&item_impl.self_ty)?;
 
         Ok(Self {
             item_impl,
@@ -436,7 +1675,6 @@ impl SummumImpl {
 
     fn render(&mut self, types: &HashMap<String, SummumType>) -> TokenStream {
         let item_impl = &mut self.item_impl;
-        let (impl_generics, _type_generics, where_clause) = item_impl.generics.split_for_impl();
 
         let item_type = if let Some(item_type) = types.get(&self.item_type_name.to_string()) {
             item_type
@@ -448,17 +1686,61 @@ impl SummumImpl {
         let mut top_enum_type = item_type.top_enum_type();
         canonicalize_type_path(&mut top_enum_type);
         let top_enum_type_string = quote!{ #top_enum_type }.to_string();
-        let (_impl_generics, type_generics, _where_clause) = item_type.generics.split_for_impl();
 
         let mut sub_type_impls: Vec<proc_macro2::TokenStream> = (0..item_type.sub_types.len())
-            .into_iter().map(|_| quote!()).collect();
+            .map(|_| quote!()).collect();
+
+        //The trait path, if we're rendering a trait impl rather than an inherent impl
+        let trait_path = item_impl.trait_.as_ref().map(|(_, path, _)| path.clone());
 
         let impl_span = item_impl.span();
         let items = core::mem::take(&mut item_impl.items);
         let mut new_items = vec![];
         for item in items.into_iter() {
+
+            //Give any abstract (bodyless) method declarations a body that delegates to the inner type
+            let item = match item {
+                ImplItem::Verbatim(tokens) => match parse_abstract_fn_decl(tokens.clone()) {
+                    Some(decl) if !item_type.sub_types.is_empty() => {
+                        return quote_spanned! {
+                            decl.sig.ident.span() => compile_error!("abstract method declarations aren't supported for sum-types declared as structs");
+                        }.into();
+                    },
+                    Some(decl) => match delegating_fn(decl, trait_path.as_ref()) {
+                        Ok(item) => ImplItem::Fn(item),
+                        Err(err) => return err.to_compile_error().into()
+                    },
+                    None => ImplItem::Verbatim(tokens)
+                },
+                item => item
+            };
+
             if let ImplItem::Fn(mut item) = item {
 
+                //A trait impl can't grow new methods, so variant-specific methods make no sense there
+                if trait_path.is_some() && item.sig.ident.to_string().ends_with("_inner_var") {
+                    return quote_spanned! {
+                        item.sig.ident.span() => compile_error!("`_inner_var` methods can't be declared in a trait impl");
+                    }.into();
+                }
+
+                //A trait method without a receiver, like `Default::default`, has no variant to dispatch on, so it's
+                // passed through as it is
+                if trait_path.is_some() && !sig_contains_self_arg(&item.sig) {
+                    if !item_type.sub_types.is_empty() {
+                        return quote_spanned! {
+                            item.sig.ident.span() => compile_error!("trait methods without a `self` argument aren't supported for sum-types declared as structs, because each sub-type's impl would need its own version");
+                        }.into();
+                    }
+                    if mentions_variant_types(item.to_token_stream()) {
+                        return quote_spanned! {
+                            item.sig.ident.span() => compile_error!("`InnerT` and `VariantT` can't be used in a trait method without a `self` argument, because there's no variant to dispatch on");
+                        }.into();
+                    }
+                    new_items.push(ImplItem::Fn(item));
+                    continue;
+                }
+
                 //Create a specialized version of the function body for each variant
                 let mut variant_blocks = vec![];
                 for (variant_idx, variant) in item_type.cases.iter().enumerate() {
@@ -470,9 +1752,9 @@ impl SummumImpl {
                     let sub_type_string = quote!{ #sub_type }.to_string();
 
                     //Swap all the occurance of `self`, etc. in the block
-                    let block_tokenstream = if item_type.sub_types.len() > 0 {
+                    let block_tokenstream = if !item_type.sub_types.is_empty() {
                         //We're processing the block for a subtype's impl
-                        replace_idents(item.block.to_token_stream(), &[
+                        replace_idents(replace_variant_type_paths(item.block.to_token_stream(), types, &ident_string), &[
                             ("VariantT", &variant_t_name),
                             ("InnerT", "Self"),
                             ("Self", &top_enum_type_string)
@@ -481,7 +1763,7 @@ impl SummumImpl {
                         ])
                     } else {
                         //We're processing the block for embedding in a match statement
-                        replace_idents(item.block.to_token_stream(), &[
+                        replace_idents(replace_variant_type_paths(item.block.to_token_stream(), types, &ident_string), &[
                             ("self", "_summum_self"),
                             ("super", "self"),
                             ("VariantT", &variant_t_name),
@@ -492,17 +1774,17 @@ impl SummumImpl {
                     };
 
                     //Handle the "exclude" and "restrict" virtual control macros in the function body
-                    let block_tokenstream = match handle_inner_macros(block_tokenstream.into(), &ident_string) {
+                    let block_tokenstream = match handle_inner_macros(block_tokenstream, &ident_string) {
                         Ok(block_tokenstream) => block_tokenstream,
                         Err(err) => {return err.into();}
                     };
 
                     //If the impl is on a struct, then we need a method impl for each sub_type
-                    if item_type.sub_types.len() > 0 {
+                    if !item_type.sub_types.is_empty() {
 
                         //Swap out `VariantT` and `InnerT` in the method signature and return value
                         let variant_t_name = format!("{}T", ident_string);
-                        let sig_tokenstream = replace_idents(item.sig.to_token_stream(), &[
+                        let sig_tokenstream = replace_idents(replace_variant_type_paths(item.sig.to_token_stream(), types, &ident_string), &[
                             ("VariantT", &variant_t_name),
                             ("InnerT", "Self"),
                         ], &[]);
@@ -525,7 +1807,13 @@ impl SummumImpl {
                         let fn_args: Vec<Ident> = item.sig.inputs.iter()
                             .filter_map(|arg| ident_for_fn_arg(arg).cloned()).collect();
 
-                        let fn_call_tokens = if sig_contains_self_arg(&item.sig) {
+                        let fn_call_tokens = if let Some(trait_path) = &trait_path {
+                            //Call through the trait, so an inherent method by the same name can't shadow it
+                            let self_arg = sig_contains_self_arg(&item.sig).then(|| quote_spanned!{item.block.span() => _summum_self, });
+                            quote_spanned!{item.block.span() =>
+                                { <#sub_type as #trait_path>::#fn_ident( #self_arg #(#fn_args),* ).into() }
+                            }
+                        } else if sig_contains_self_arg(&item.sig) {
                             quote_spanned!{item.block.span() =>
                                 { _summum_self.#fn_ident( #(#fn_args),* ).into() }
                             }
@@ -554,19 +1842,20 @@ impl SummumImpl {
                         let mut new_item = item.clone();
 
                         new_item.attrs.push(parse_quote! {
-                            #[allow(dead_code)]
+                            #[allow(dead_code, unreachable_patterns)]
                         });
+                        new_item.attrs.extend(cfg_attrs(&variant.attrs));
                         let item_type_name = self.item_type_name.to_string();
                         let ident = &variant.ident;
                         let ident_string = ident.to_string();
                         let new_method_name = snake_name(base_fn_name, &ident_string);
                         new_item.sig.ident = Ident::new(&new_method_name, item.sig.ident.span());
 
-                        //Swap out `VariantT` and `InnerT` in the method signature and return value
+                        //Swap out `OtherType::VariantT`, `VariantT` and `InnerT` in the method signature and return value
                         let variant_t_name = format!("{}T", ident_string);
                         let sub_type = type_from_fields(&variant.fields);
                         let sub_type_string = quote!{ #sub_type }.to_string();
-                        let sig_tokenstream = replace_idents(new_item.sig.to_token_stream(), &[
+                        let sig_tokenstream = replace_idents(replace_variant_type_paths(new_item.sig.to_token_stream(), types, &ident_string), &[
                             ("VariantT", &variant_t_name),
                             ("InnerT", &sub_type_string),
                         ], &[]);
@@ -574,11 +1863,12 @@ impl SummumImpl {
 
                         //If we have a `self` input arg
                         new_item.block = if sig_contains_self_arg(&new_item.sig) {
+                            let match_arm = variant_match_arm(quote!{ Self:: }, variant, &quote_spanned!{item.span() => _summum_self }, block.to_token_stream());
                             parse(quote_spanned!{item.span() =>
                                 {
                                     match self{
-                                        Self::#ident(_summum_self) => #block ,
-                                        _ => panic!("`{}::{}` method must be called with corresponding inner type", #item_type_name, #new_method_name)
+                                        #match_arm ,
+                                        _ => core::panic!("`{}::{}` method must be called with corresponding inner type", #item_type_name, #new_method_name)
                                     }
                                 }
                             }.into()).unwrap()
@@ -593,10 +1883,7 @@ impl SummumImpl {
 
                     //If the method name doesn't end with "inner_var", we'll generate just one method
                     let match_arms = item_type.cases.iter().zip(variant_blocks).map(|(variant, block)| {
-                        let ident = &variant.ident;
-                        quote_spanned! {item.span() =>
-                            Self::#ident(_summum_self) => #block
-                        }
+                        variant_match_arm(quote!{ Self:: }, variant, &quote_spanned!{item.span() => _summum_self }, block.to_token_stream())
                     }).collect::<Vec<_>>();
 
                     item.block = parse(quote_spanned!{item.span() =>
@@ -609,9 +1896,27 @@ impl SummumImpl {
                     new_items.push(ImplItem::Fn(item));
                 }
             } else {
+                //If the impl is on a struct, carry the consts, types, and macros forward into each sub_type's
+                // impl, performing the same substitutions we do on the method bodies
+                for (sub_type_impl, variant) in sub_type_impls.iter_mut().zip(item_type.cases.iter()) {
+                    let ident_string = variant.ident.to_string();
+                    let variant_t_name = format!("{}T", ident_string);
+                    sub_type_impl.extend(replace_idents(replace_variant_type_paths(item.to_token_stream(), types, &ident_string), &[
+                        ("VariantT", &variant_t_name),
+                        ("InnerT", "Self"),
+                        ("Self", &top_enum_type_string)
+                    ], &[
+                        ("_inner_var", &|base| snake_name(base, &ident_string))
+                    ]));
+                }
+
                 //QUESTION: Do I also need to perform type-substitution on non-fn items here?
                 //ANSWER: I don't think so because InnerT and VariantT are both meaningless in
-                // code that spans all variants
+                // code that spans all variants.  So in an inherent impl on a struct, we leave
+                // those items to the sub_types.  A trait impl needs all of its items, however.
+                if trait_path.is_none() && !item_type.sub_types.is_empty() && mentions_variant_types(item.to_token_stream()) {
+                    continue;
+                }
                 new_items.push(item);
             }
         }
@@ -621,11 +1926,35 @@ impl SummumImpl {
             .zip(item_type.sub_types.iter())
             .map(|(sub_type_impl_fns, sub_type)| {
             let variant_name = sub_type.struct_type_ident(&item_type.name);
+            let trait_for = trait_path.as_ref().map(|trait_path| quote!{ #trait_path for });
+
+            //The sub-type may not use all of the impl's generics
+            let (_impl_generics, sub_type_generics, _where_clause) = sub_type.generics.split_for_impl();
+            let sub_type_impl_generics = used_generics(&item_impl.generics, sub_type_generics.to_token_stream());
+            let cfgs = cfg_attrs(&sub_type.attrs);
+
+            //Padding the sub-type with the params it doesn't use would need every impl to be known when the
+            // sub-type is defined, which isn't the case with `summum_impl!`, so referencing them is an error
+            let mut dropped_idents = HashSet::new();
+            let mut dropped_lifetimes = HashSet::new();
+            for param in item_impl.generics.params.iter().filter(|param| !sub_type_impl_generics.params.iter().any(|kept| kept == *param)) {
+                match param {
+                    GenericParam::Lifetime(lifetime_param) => { dropped_lifetimes.insert(lifetime_param.lifetime.ident.to_string()); },
+                    GenericParam::Type(type_param) => { dropped_idents.insert(type_param.ident.to_string()); },
+                    GenericParam::Const(const_param) => { dropped_idents.insert(const_param.ident.to_string()); },
+                }
+            }
+            if let Some((param_ident, is_lifetime)) = find_ident(sub_type_impl_fns.clone(), &dropped_idents, &dropped_lifetimes) {
+                let apostrophe = if is_lifetime { "'" } else { "" };
+                let message = format!("`{apostrophe}{param_ident}` can't be used in the methods of `{variant_name}`, because its fields don't use it");
+                return quote_spanned!{param_ident.span() => compile_error!(#message);};
+            }
 
-            //TODO, Also need to carry forward the non-function items from the item_impl.items field
-            // Which then means I also need to do type substitution on them
-            quote_spanned!{impl_span => 
-                impl #impl_generics #variant_name #type_generics #where_clause {
+            let (sub_type_impl_generics, _type_generics, sub_type_where_clause) = sub_type_impl_generics.split_for_impl();
+
+            quote_spanned!{impl_span =>
+                #(#cfgs)*
+                impl #sub_type_impl_generics #trait_for #variant_name #sub_type_generics #sub_type_where_clause {
                     #sub_type_impl_fns
                 }
             }
@@ -661,10 +1990,73 @@ impl Parse for SummumItems {
             }
         }
 
+        resolve_spreads(&mut items.types)?;
         Ok(items)
     }
 }
 
+/// Inlines the variants of each `..OtherType` spread into the sum-type it was spread into
+fn resolve_spreads(types: &mut HashMap<String, SummumType>) -> Result<()> {
+    let is_resolved = |item_type: &SummumType| item_type.spreads.iter().all(|spread| spread.cases.is_some());
+
+    //Spread types may contain spreads of their own, so we keep resolving the types whose spread types are
+    // already resolved, until there's nothing left to do
+    loop {
+        let mut pending = types.values().filter(|item_type| !is_resolved(item_type)).map(|item_type| item_type.name.to_string()).collect::<Vec<_>>();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        pending.sort();
+
+        let mut progress = false;
+        for type_name in pending.iter() {
+            let item_type = &types[type_name];
+            let mut spread_cases = vec![];
+            let mut inherited_spreads = vec![];
+            for spread in item_type.spreads.iter() {
+                let spread_type = match types.get(&spread.type_name.to_string()) {
+                    Some(spread_type) => spread_type,
+                    None => return Err(Error::new(spread.type_name.span(), format!("`..{}` must name a sum-type defined in the same `summum` invocation", spread.type_name)))
+                };
+                if !spread_type.sub_types.is_empty() || spread_type.generics.to_token_stream().to_string() != item_type.generics.to_token_stream().to_string() {
+                    return Err(Error::new(spread.type_name.span(), format!("`..{}` must name a sum-type that isn't a struct, and has the same generics as `{}`", spread.type_name, item_type.name)));
+                }
+                if !is_resolved(spread_type) {
+                    break;
+                }
+                spread_cases.push((spread_type.cases.clone(), spread_type.options.views));
+
+                //The types spread into the spread type are spread into this type too, so they get accessors as well
+                for inherited in spread_type.spreads.iter() {
+                    inherited_spreads.push(Spread{ type_name: inherited.type_name.clone(), position: 0, cases: inherited.cases.clone(), has_views: inherited.has_views });
+                }
+            }
+            if spread_cases.len() < item_type.spreads.len() {
+                continue;
+            }
+
+            //Insert from the back, so the positions of the earlier spreads stay valid
+            let item_type = types.get_mut(type_name).unwrap();
+            for (spread, (cases, has_views)) in item_type.spreads.iter_mut().zip(spread_cases).rev() {
+                item_type.cases.splice(spread.position..spread.position, cases.iter().cloned());
+                spread.cases = Some(cases);
+                spread.has_views = has_views;
+            }
+            for inherited in inherited_spreads {
+                if !item_type.spreads.iter().any(|spread| spread.type_name == inherited.type_name) {
+                    item_type.spreads.push(inherited);
+                }
+            }
+            progress = true;
+        }
+
+        if !progress {
+            let spread = &types[&pending[0]].spreads[0];
+            return Err(Error::new(spread.type_name.span(), format!("`..{}` can't be resolved, because the sum-types are spread into each other", spread.type_name)));
+        }
+    }
+}
+
 /// See the crate's top-level for usage docs
 #[proc_macro]
 pub fn summum(input: TokenStream) -> TokenStream {
@@ -675,6 +2067,110 @@ pub fn summum(input: TokenStream) -> TokenStream {
         new_stream.extend(item.render());
     }
 
+    //Widening and narrowing conversions between types whose variants are a subset of another type's, if they
+    // were asked for
+    for small in items.types.values() {
+        for big in items.types.values() {
+            new_stream.extend(TokenStream::from(small.render_conversions(big)));
+        }
+    }
+
+    for item_impl in items.impls.iter_mut() {
+        new_stream.extend(item_impl.render(&items.types));
+    }
+
+    new_stream
+}
+
+/// Defines a sum-type from an ordinary `enum`, with all the same conversions and accessors as [summum!]
+///
+/// Methods can then be implemented elsewhere with [summum_impl!].  See the crate's top-level for usage docs
+#[proc_macro_attribute]
+pub fn summum_type(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return quote_spanned!{attr.span() => compile_error!("unexpected arguments to `summum_type`");}.into();
+    }
+    let parser = |input: ParseStream| {
+        let attrs = input.call(Attribute::parse_outer)?;
+        SummumType::parse(input, attrs)
+    };
+    match parser.parse(item) {
+        Ok(item_type) => item_type.render(),
+        Err(err) => err.to_compile_error().into()
+    }
+}
+
+/// Generates the same conversions and accessors as [summum!] for an existing `enum`, without taking over its definition
+///
+/// Variants with several fields or named fields are accessed as a tuple, and unit variants as `()`.  Methods can be
+/// implemented with [summum_impl!].
+/// See the crate's top-level for usage docs
+#[proc_macro_derive(SummumAccessors, attributes(summum))]
+pub fn derive_summum_accessors(input: TokenStream) -> TokenStream {
+    let parser = |input: ParseStream| {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        if !input.peek(Token![enum]) {
+            return Err(input.error("`SummumAccessors` can only be derived for an `enum`"));
+        }
+        SummumType::parse_enum_style(input, attrs, vis)
+    };
+    match parser.parse(input) {
+        Ok(item_type) => {
+            let mut new_stream = item_type.render_impls();
+            if item_type.options.views {
+                new_stream.extend(item_type.render_views());
+            }
+            new_stream.into()
+        },
+        Err(err) => err.to_compile_error().into()
+    }
+}
+
+/// Implements methods for a sum-type defined in another `summum!` invocation, or with [macro@summum_type]
+///
+/// The sum-type's hidden `__summum_SumT` macro must be in scope, e.g. by glob-importing the module where
+/// the sum-type is defined, or the `impl` must name the sum-type by its path.  See the crate's top-level
+/// for usage docs
+#[proc_macro]
+pub fn summum_impl(input: TokenStream) -> TokenStream {
+    let parser = |input: ParseStream| {
+        let mut item_impls = vec![];
+        while !input.is_empty() {
+            item_impls.push(input.parse::<ItemImpl>()?);
+        }
+        Ok(item_impls)
+    };
+    let item_impls = match parser.parse(input) {
+        Ok(item_impls) => item_impls,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    //Call each sum-type's callback macro, which will call back into `__summum_impl` with the type definition
+    let mut new_stream = proc_macro2::TokenStream::new();
+    for item_impl in item_impls {
+        let mut callback_path = match &*item_impl.self_ty {
+            Type::Path(type_path) if type_path.qself.is_none() => type_path.path.clone(),
+            self_ty => return Error::new(self_ty.span(), "expected a path to a sum-type").to_compile_error().into()
+        };
+        let last_segment = callback_path.segments.last_mut().unwrap();
+        last_segment.ident = callback_macro_ident(&last_segment.ident);
+        last_segment.arguments = PathArguments::None;
+        new_stream.extend(quote!{
+            #callback_path!{ #item_impl }
+        });
+    }
+    new_stream.into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __summum_impl(input: TokenStream) -> TokenStream {
+    let mut new_stream = TokenStream::new();
+    let mut items: SummumItems = parse_macro_input!(input as SummumItems);
+
+    //The types have already been rendered where they were defined, so we only render the impls
     for item_impl in items.impls.iter_mut() {
         new_stream.extend(item_impl.render(&items.types));
     }
@@ -682,6 +2178,58 @@ pub fn summum(input: TokenStream) -> TokenStream {
     new_stream
 }
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __summum_dispatch(input: TokenStream) -> TokenStream {
+    let parser = |input: ParseStream| {
+        let definition_content: ParseBuffer;
+        let _brace_token = syn::braced!(definition_content in input);
+        let attrs = definition_content.call(Attribute::parse_outer)?;
+        let item_type = SummumType::parse(&definition_content, attrs)?;
+
+        let sum_expr_content: ParseBuffer;
+        let _paren_token = syn::parenthesized!(sum_expr_content in input);
+        let inner_pat_content: ParseBuffer;
+        let _paren_token = syn::parenthesized!(inner_pat_content in input);
+
+        Ok((item_type, sum_expr_content.parse()?, inner_pat_content.parse()?, input.parse()?))
+    };
+    let (item_type, sum_expr, inner_pat, body) = match parser.parse(input) {
+        Ok(parsed) => parsed,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    //The type goes in a map so `SumT::VariantT` can be used in the body
+    let type_name = item_type.name.to_string();
+    let types = HashMap::from([(type_name.clone(), item_type)]);
+    types[&type_name].render_dispatch(&types, sum_expr, inner_pat, body)
+}
+
+/// The identifier for a sum-type's callback macro, e.g. `__summum_Num`
+fn callback_macro_ident(type_name: &Ident) -> Ident {
+    Ident::new(&format!("__summum_{type_name}"), type_name.span())
+}
+
+/// The identifier for a sum-type's conversion error type, e.g. `NumTryFromError`
+fn try_from_error_ident(type_name: &Ident) -> Ident {
+    Ident::new(&format!("{type_name}TryFromError"), type_name.span())
+}
+
+/// The identifier for a sum-type's fieldless kind enum, e.g. `NumKind`
+fn kind_ident(type_name: &Ident) -> Ident {
+    Ident::new(&format!("{type_name}Kind"), type_name.span())
+}
+
+/// The identifier for the error parsing a sum-type's kind, e.g. `NumKindParseError`
+fn kind_parse_error_ident(type_name: &Ident) -> Ident {
+    Ident::new(&format!("{type_name}KindParseError"), type_name.span())
+}
+
+/// The identifier for a sum-type's dispatch macro, e.g. `num_dispatch`
+fn dispatch_macro_ident(type_name: &Ident) -> Ident {
+    Ident::new(&snake_name(&AsSnakeCase(type_name.to_string()).to_string(), "dispatch"), type_name.span())
+}
+
 /// Renders the entire type, including lifetimes and generics, into a single legal identifier token
 fn ident_from_type_full(item_type: &Type) -> Ident {
     let item_ident = quote!{ #item_type }.to_string();
@@ -689,20 +2237,132 @@ fn ident_from_type_full(item_type: &Type) -> Ident {
     Ident::new(&item_ident, item_type.span())
 }
 
+/// The identifier for the module containing a sum-type's variant markers, e.g. `num_variants`
+fn variants_mod_ident(type_name: &Ident) -> Ident {
+    Ident::new(&snake_name(&AsSnakeCase(type_name.to_string()).to_string(), "variants"), type_name.span())
+}
+
+/// The identifier for the alias of a variant's inner type, e.g. `NumF64T`
+fn variant_type_alias_ident(type_name: &Ident, variant_ident: &Ident) -> Ident {
+    Ident::new(&format!("{type_name}{variant_ident}T"), variant_ident.span())
+}
+
 fn snake_name(base: &str, ident: &str) -> String {
     format!("{base}_{}", AsSnakeCase(ident))
 }
 
-fn type_from_fields(fields: &Fields) -> &Type {
-    if let Fields::Unnamed(field) = fields {
-        &field.unnamed.first().unwrap().ty
-    } else {panic!()}
+/// The inner type of a variant, which is the type of its field, or a tuple of its fields' types if it has
+/// several.  A unit variant's inner type is `()`
+fn type_from_fields(fields: &Fields) -> Type {
+    if fields.len() == 1 {
+        fields.iter().next().unwrap().ty.clone()
+    } else {
+        let field_types = fields.iter().map(|field| &field.ty);
+        parse_quote!{ (#(#field_types),*) }
+    }
+}
+
+/// Renders the type of a reference to a variant's inner value, where `reference` is e.g. `&'a mut`.  For a
+/// variant with several fields, this is a tuple of references because there is no tuple in memory to refer to
+fn ref_type_from_fields(fields: &Fields, reference: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let field_types = fields.iter().map(|field| &field.ty);
+    if fields.len() == 1 {
+        quote!{ #reference #(#field_types)* }
+    } else {
+        quote!{ (#(#reference #field_types),*) }
+    }
+}
+
+/// The identifiers that bind each of a variant's fields in a match pattern, e.g. `_summum_0`
+fn field_bindings(fields: &Fields) -> Vec<Ident> {
+    (0..fields.len()).map(|idx| Ident::new(&format!("_summum_{idx}"), Span::call_site())).collect()
+}
+
+/// Renders a variant with each field taken from `field_tokens`, as either a pattern or an expression,
+/// e.g. `Pair(_summum_0, _summum_1)` or `Point{ x: val.0, y: val.1 }`
+fn variant_with_fields<T: ToTokens>(ident: &Ident, fields: &Fields, field_tokens: &[T]) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Named(named) => {
+            let field_idents = named.named.iter().map(|field| &field.ident);
+            quote!{ #ident{ #(#field_idents: #field_tokens),* } }
+        },
+        Fields::Unnamed(_) => quote!{ #ident( #(#field_tokens),* ) },
+        Fields::Unit => quote!{ #ident },
+    }
+}
+
+/// Renders the pattern for an argument that takes the inner value of a variant.  A unit variant's inner
+/// value is `()`, so there's nothing to bind
+fn inner_value_arg_pat(fields: &Fields, val: &Ident) -> proc_macro2::TokenStream {
+    if fields.is_empty() {
+        quote!{ _ }
+    } else {
+        quote!{ #val }
+    }
+}
+
+/// Returns the `#[cfg]` attributes of a variant, as well as any `#[cfg_attr]` that only wraps `cfg`s.  These
+/// need to be copied onto everything that's generated for the variant, so nothing refers to a missing variant
+fn cfg_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs.iter().filter(|attr| {
+        if attr.path().is_ident("cfg") {
+            return true;
+        }
+        if attr.path().is_ident("cfg_attr") {
+            if let Ok(metas) = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+                return metas.len() > 1 && metas.iter().skip(1).all(|meta| meta.path().is_ident("cfg"));
+            }
+        }
+        false
+    }).cloned().collect()
+}
+
+/// Renders a match arm that binds the inner value of `variant` to `inner_pat`, and then evaluates `block`
+fn variant_match_arm(path_prefix: proc_macro2::TokenStream, variant: &Variant, inner_pat: &proc_macro2::TokenStream, block: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let cfgs = cfg_attrs(&variant.attrs);
+    if variant.fields.len() == 1 {
+        let variant_pattern = variant_with_fields(&variant.ident, &variant.fields, &[inner_pat]);
+        quote_spanned!{variant.span() =>
+            #(#cfgs)* #path_prefix #variant_pattern => #block
+        }
+    } else {
+        let bindings = field_bindings(&variant.fields);
+        let variant_pattern = variant_with_fields(&variant.ident, &variant.fields, &bindings);
+        let inner_value = inner_value_from_bindings(&bindings);
+        quote_spanned!{variant.span() =>
+            #(#cfgs)* #path_prefix #variant_pattern => {
+                let #inner_pat = #inner_value;
+                #block
+            }
+        }
+    }
+}
+
+/// Renders an expression that packs the bound fields of a variant into its inner value
+fn inner_value_from_bindings(bindings: &[Ident]) -> proc_macro2::TokenStream {
+    match bindings {
+        [binding] => quote!{ #binding },
+        bindings => quote!{ (#(#bindings),*) },
+    }
+}
+
+/// Renders the expressions that unpack each field of a variant from the inner value `val`
+fn fields_from_inner_value(fields: &Fields, val: &Ident) -> Vec<proc_macro2::TokenStream> {
+    if fields.len() == 1 {
+        vec![quote!{ #val }]
+    } else {
+        (0..fields.len()).map(|idx| {
+            let idx = syn::Index::from(idx);
+            quote!{ #val.#idx }
+        }).collect()
+    }
 }
 
-fn type_from_fields_mut(fields: &mut Fields) -> &mut Type {
-    if let Fields::Unnamed(field) = fields {
-        &mut field.unnamed.first_mut().unwrap().ty
-    } else {panic!()}
+/// Canonicalizes the type of each of a variant's fields with [canonicalize_type_path]
+fn canonicalize_field_types(fields: &mut Fields) {
+    for field in fields.iter_mut() {
+        canonicalize_type_path(&mut field.ty);
+    }
 }
 
 /// Transforms `MyType<'a, T>` into `MyType::<'a, T>`
@@ -720,6 +2380,30 @@ fn canonicalize_type_path(item_type: &mut Type) {
     }
 }
 
+/// Returns the rendered inner types that are shared by more than one variant
+fn overlapping_inner_types(cases: &[Variant]) -> HashSet<String> {
+    let mut seen = HashSet::new();
+    let mut overlapping = HashSet::new();
+    for variant in cases {
+        let type_string = type_from_fields(&variant.fields).to_token_stream().to_string();
+        if !seen.insert(type_string.clone()) {
+            overlapping.insert(type_string);
+        }
+    }
+    overlapping
+}
+
+/// Renders a variant's fields and `cfg` attributes, but not its name, so variants of different types can
+/// be compared
+fn variant_shape(variant: &Variant) -> String {
+    let mut fields = variant.fields.clone();
+    for field in fields.iter_mut() {
+        field.attrs.clear();
+    }
+    let cfgs = cfg_attrs(&variant.attrs);
+    quote!{ #(#cfgs)* #fields }.to_string()
+}
+
 /// Detect the situation where we'd get the error: https://doc.rust-lang.org/error_codes/E0210.html
 /// `type parameter `T` must be covered by another type when it appears before the first local type...`
 fn detect_uncovered_type(generic_type_params: &[&TypeParam], item_type: &Type) -> bool {
@@ -727,7 +2411,7 @@ fn detect_uncovered_type(generic_type_params: &[&TypeParam], item_type: &Type) -
         Type::Path(type_path) => {
             if let Some(type_ident) = type_path.path.get_ident() {
                 for generic_type_params in generic_type_params {
-                    if generic_type_params.ident.to_string() == type_ident.to_string() {
+                    if generic_type_params.ident == *type_ident {
                         return true;
                     }
                 }
@@ -761,13 +2445,128 @@ fn type_params_from_generics(generics: &Generics) -> Vec<&TypeParam> {
     results
 }
 
+/// Collects every identifier and lifetime that occurs anywhere in a TokenStream
+fn collect_idents(input: proc_macro2::TokenStream, idents: &mut HashSet<String>, lifetimes: &mut HashSet<String>) {
+    let mut after_apostrophe = false;
+    for item in input.into_iter() {
+        let is_apostrophe = matches!(&item, TokenTree::Punct(p) if p.as_char() == '\'');
+        match item {
+            TokenTree::Ident(ident) if after_apostrophe => { lifetimes.insert(ident.to_string()); },
+            TokenTree::Ident(ident) => { idents.insert(ident.to_string()); },
+            TokenTree::Group(group) => collect_idents(group.stream(), idents, lifetimes),
+            _ => {}
+        }
+        after_apostrophe = is_apostrophe;
+    }
+}
+
+/// Returns the paths of all the traits in the `#[derive(...)]` attributes
+fn derived_traits(attrs: &[Attribute]) -> Vec<Path> {
+    attrs.iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated).ok())
+        .flatten()
+        .collect()
+}
+
+/// Returns `true` if the last segment of the path is `ident`, e.g. `core::fmt::Debug` ends with `Debug`
+fn path_ends_with(path: &Path, ident: &str) -> bool {
+    path.segments.last().map(|segment| segment.ident == ident).unwrap_or(false)
+}
+
+/// Returns the first identifier in a TokenStream whose name is in `idents`, or lifetime whose name is in
+/// `lifetimes`, along with whether it was a lifetime
+fn find_ident(input: proc_macro2::TokenStream, idents: &HashSet<String>, lifetimes: &HashSet<String>) -> Option<(Ident, bool)> {
+    let mut after_apostrophe = false;
+    for item in input.into_iter() {
+        let is_apostrophe = matches!(&item, TokenTree::Punct(p) if p.as_char() == '\'');
+        match item {
+            TokenTree::Ident(ident) if after_apostrophe && lifetimes.contains(&ident.to_string()) => return Some((ident, true)),
+            TokenTree::Ident(ident) if !after_apostrophe && idents.contains(&ident.to_string()) => return Some((ident, false)),
+            TokenTree::Group(group) => if let Some(found) = find_ident(group.stream(), idents, lifetimes) {
+                return Some(found);
+            },
+            _ => {}
+        }
+        after_apostrophe = is_apostrophe;
+    }
+    None
+}
+
+/// Returns the subset of `generics` referenced in `tokens`.  Where-clause predicates are kept only
+/// if they don't mention any of the params that were dropped
+fn used_generics(generics: &Generics, tokens: proc_macro2::TokenStream) -> Generics {
+    let mut idents = HashSet::new();
+    let mut lifetimes = HashSet::new();
+    collect_idents(tokens, &mut idents, &mut lifetimes);
+
+    let (kept, dropped): (Vec<GenericParam>, Vec<GenericParam>) = generics.params.iter().cloned().partition(|param| match param {
+        GenericParam::Lifetime(lifetime_param) => lifetimes.contains(&lifetime_param.lifetime.ident.to_string()),
+        GenericParam::Type(type_param) => idents.contains(&type_param.ident.to_string()),
+        GenericParam::Const(const_param) => idents.contains(&const_param.ident.to_string()),
+    });
+
+    let mut new_generics = generics.clone();
+    new_generics.params = kept.into_iter().collect();
+    if let Some(where_clause) = &mut new_generics.where_clause {
+        let mut dropped_idents = HashSet::new();
+        let mut dropped_lifetimes = HashSet::new();
+        for param in dropped.iter() {
+            match param {
+                GenericParam::Lifetime(lifetime_param) => { dropped_lifetimes.insert(lifetime_param.lifetime.ident.to_string()); },
+                GenericParam::Type(type_param) => { dropped_idents.insert(type_param.ident.to_string()); },
+                GenericParam::Const(const_param) => { dropped_idents.insert(const_param.ident.to_string()); },
+            }
+        }
+        where_clause.predicates = where_clause.predicates.iter().filter(|predicate| {
+            let mut idents = HashSet::new();
+            let mut lifetimes = HashSet::new();
+            collect_idents(predicate.to_token_stream(), &mut idents, &mut lifetimes);
+            idents.is_disjoint(&dropped_idents) && lifetimes.is_disjoint(&dropped_lifetimes)
+        }).cloned().collect();
+    }
+    new_generics
+}
+
+/// Returns `true` if the tokens reference `InnerT` or `VariantT`, and therefore need a specific variant to make sense
+fn mentions_variant_types(tokens: proc_macro2::TokenStream) -> bool {
+    let mut idents = HashSet::new();
+    collect_idents(tokens, &mut idents, &mut HashSet::new());
+    idents.contains("InnerT") || idents.contains("VariantT")
+}
+
+/// Strips the bounds and the where clause from generics, e.g. for use in a type alias
+fn bare_generic_params(generics: &Generics) -> Generics {
+    let mut new_generics = generics.clone();
+    new_generics.where_clause = None;
+    for param in new_generics.params.iter_mut() {
+        match param {
+            GenericParam::Lifetime(lifetime_param) => {
+                lifetime_param.colon_token = None;
+                lifetime_param.bounds.clear();
+            },
+            GenericParam::Type(type_param) => {
+                type_param.colon_token = None;
+                type_param.bounds.clear();
+                type_param.eq_token = None;
+                type_param.default = None;
+            },
+            GenericParam::Const(const_param) => {
+                const_param.eq_token = None;
+                const_param.default = None;
+            },
+        }
+    }
+    new_generics
+}
+
 /// Helper object to parse an identifier from a compound type with generics
 struct TypeIdentParseHelper(Ident);
 
 impl Parse for TypeIdentParseHelper {
     fn parse(input: ParseStream) -> Result<Self> {
 
-        let mut result = Err(Error::new(input.span(), format!("invalid type")));
+        let mut result = Err(Error::new(input.span(), "invalid type"));
         while !input.is_empty() {
             if input.peek(Ident) {
                 let ident = input.parse::<Ident>()?;
@@ -785,16 +2584,26 @@ impl Parse for TypeIdentParseHelper {
 
 /// Renders the name of a type into a single legal identifier token, stripping away generics and lifetimes
 fn ident_from_type_short(item_type: &Type) -> Result<Ident> {
+    //A path to a type, e.g. `crate::nums::Num<T>`, is named by its last segment
+    if let Type::Path(type_path) = item_type {
+        if let Some(segment) = type_path.path.segments.last() {
+            return Ok(segment.ident.clone());
+        }
+    }
     let type_stream = quote!{ #item_type };
     let ident: TypeIdentParseHelper = parse2(type_stream)?;
     Ok(ident.0)
 }
 
+/// A table of ident suffixes, and the function to compute the replacement from the prefix
+type EndsWithMap<'a> = [(&'a str, &'a dyn Fn(&str) -> String)];
+
 /// Do a depth-first traversal of a TokenStream replacing each ident in a map with another ident
-fn replace_idents(input: proc_macro2::TokenStream, map: &[(&str, &str)], ends_with_map: &[(&str, &dyn Fn(&str) -> String)]) -> proc_macro2::TokenStream {
+fn replace_idents(input: proc_macro2::TokenStream, map: &[(&str, &str)], ends_with_map: &EndsWithMap) -> proc_macro2::TokenStream {
     let mut new_stream = proc_macro2::TokenStream::new();
 
-    for item in input.into_iter() {
+    let mut input_iter = input.into_iter().peekable();
+    while let Some(item) = input_iter.next() {
         match item {
             TokenTree::Ident(ident) => {
                 let ident_string = ident.to_string();
@@ -816,7 +2625,15 @@ fn replace_idents(input: proc_macro2::TokenStream, map: &[(&str, &str)], ends_wi
                     let replacement_stream = parse_str::<proc_macro2::TokenStream>(&replacement_string).expect("Error rendering type back to tokens");
                     let replacement_stream: proc_macro2::TokenStream = replacement_stream.into_iter()
                         .map(|mut item| {item.set_span(ident.span()); item} ).collect();
-                    new_stream.extend([replacement_stream]);
+
+                    //A type like `(f64, f64)` or `&str` needs angle brackets to be the start of a path, e.g. `<(f64, f64)>::default()`
+                    let starts_path = matches!(input_iter.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == ':' && punct.spacing() == Spacing::Joint);
+                    let is_path = matches!(replacement_stream.clone().into_iter().next(), Some(TokenTree::Ident(_)));
+                    if starts_path && !is_path {
+                        new_stream.extend(quote_spanned!{ident.span() => < #replacement_stream > });
+                    } else {
+                        new_stream.extend([replacement_stream]);
+                    }
                 } else {
                     new_stream.extend([TokenTree::Ident(ident)]);
                 }
@@ -834,7 +2651,61 @@ fn replace_idents(input: proc_macro2::TokenStream, map: &[(&str, &str)], ends_wi
     new_stream
 }
 
-const MACRO_IDENT_LIST: &'static[&'static str] = &["summum_exclude", "summum_restrict", "summum_variant_name"];
+/// Do a depth-first traversal of a TokenStream replacing each `OtherType::VariantT` path with the inner
+/// type of `OtherType`'s variant named `variant_ident`, for any `OtherType` defined in the summum block
+fn replace_variant_type_paths(input: proc_macro2::TokenStream, types: &HashMap<String, SummumType>, variant_ident: &str) -> proc_macro2::TokenStream {
+    let mut new_stream = proc_macro2::TokenStream::new();
+
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let mut idx = 0;
+    while idx < tokens.len() {
+        if let Some(sub_type) = variant_type_path_at(&tokens[idx..], types, variant_ident) {
+            let replacement_stream: proc_macro2::TokenStream = sub_type.to_token_stream().into_iter()
+                .map(|mut item| {item.set_span(tokens[idx].span()); item} ).collect();
+            new_stream.extend([replacement_stream]);
+            idx += 4;
+            continue;
+        }
+        match &tokens[idx] {
+            TokenTree::Group(group) => {
+                let new_group_stream = replace_variant_type_paths(group.stream(), types, variant_ident);
+                let mut new_group = Group::new(group.delimiter(), new_group_stream);
+                new_group.set_span(group.span());
+                new_stream.extend([TokenTree::Group(new_group)]);
+            },
+            item => {new_stream.extend([item.clone()]);}
+        }
+        idx += 1;
+    }
+
+    new_stream
+}
+
+/// Matches the four tokens of `OtherType::VariantT` at the start of `tokens`, and returns the inner type
+fn variant_type_path_at(tokens: &[TokenTree], types: &HashMap<String, SummumType>, variant_ident: &str) -> Option<Type> {
+    if let [TokenTree::Ident(type_ident), TokenTree::Punct(colon_a), TokenTree::Punct(colon_b), TokenTree::Ident(variant_t), ..] = tokens {
+        if colon_a.as_char() == ':' && colon_b.as_char() == ':' && variant_t == "VariantT" {
+            let variant = types.get(&type_ident.to_string())?
+                .cases.iter().find(|variant| variant.ident == variant_ident)?;
+            return Some(type_from_fields(&variant.fields));
+        }
+    }
+    None
+}
+
+/// The traits that can be implemented with `#[summum(delegate(...))]`
+const DELEGATE_TRAITS: &[&str] = &["Display", "LowerHex", "UpperHex", "Octal", "Binary", "LowerExp", "UpperExp", "Pointer", "Iterator", "Future", "Error", "Read", "BufRead", "Write", "Seek"];
+
+/// The derivable traits that are carried over from a sum-type to its `SumTRef` and `SumTMut` views
+const VIEW_DERIVES: &[&str] = &["Debug", "PartialEq", "Eq", "PartialOrd", "Ord", "Hash"];
+
+/// The delegated traits that are also implemented for the views, because they behave the same through a reference
+const VIEW_DELEGATE_TRAITS: &[&str] = &["Display", "LowerHex", "UpperHex", "Octal", "Binary", "LowerExp", "UpperExp"];
+
+/// The prefixes of the accessors generated for each variant, e.g. `is` for `is_f64`
+const ACCESSOR_PREFIXES: &[&str] = &["from", "is", "try_as", "as", "try_as_mut", "as_mut", "try_into", "into"];
+
+const MACRO_IDENT_LIST: &[&str] = &["summum_exclude", "summum_restrict", "summum_variant_name"];
 
 //Implement the "summum_exclude!" and "summum_restrict!" virtual macros
 fn handle_inner_macros(input: proc_macro2::TokenStream, branch_ident: &str) -> core::result::Result<proc_macro2::TokenStream, proc_macro2::TokenStream> {
@@ -865,7 +2736,7 @@ fn handle_inner_macros(input: proc_macro2::TokenStream, branch_ident: &str) -> c
                         let macro_args: Vec<String> = macro_args_punct.into_iter().map(|ident| ident.to_string()).collect();
                         macro_args
                     } else {
-                        return Err(quote_spanned! {next_span => compile_error!("Expecting tuple for macro args"); }.into());
+                        return Err(quote_spanned! {next_span => compile_error!("Expecting tuple for macro args"); });
                     };
                     if parse_punct(input_iter.peek(), ';').is_ok() {
                         let _ = input_iter.next();
@@ -881,7 +2752,7 @@ fn handle_inner_macros(input: proc_macro2::TokenStream, branch_ident: &str) -> c
                                 let panic_tokens = quote_spanned!{ident.span() =>
                                     {
                                         #new_stream
-                                        panic!(#unreachable_message);
+                                        core::panic!(#unreachable_message);
                                         // #[allow(unreachable_code)]
                                     }
                                 };
@@ -921,16 +2792,65 @@ fn parse_punct<T: core::borrow::Borrow<TokenTree>>(item: Option<T>, the_char: ch
         }
     }
     let err_string = format!("expecting {the_char}");
-    return Err(quote_spanned! {span => compile_error!(#err_string); }.into());
+    Err(quote_spanned! {span => compile_error!(#err_string); })
 }
 
 fn sig_contains_self_arg(sig: &Signature) -> bool {
-    if let Some(first_arg) = sig.inputs.first() {
-        if let FnArg::Receiver(_rcvr) = first_arg {
-            return true;
+    matches!(sig.inputs.first(), Some(FnArg::Receiver(_)))
+}
+
+/// Parses a method declaration without a body, e.g. `fn len(&self) -> usize;`, which syn leaves
+/// as verbatim tokens inside an impl block
+fn parse_abstract_fn_decl(tokens: proc_macro2::TokenStream) -> Option<ImplItemFn> {
+    let parser = |input: ParseStream| {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let sig = input.parse()?;
+        input.parse::<Token![;]>()?;
+        Ok(ImplItemFn {
+            attrs,
+            vis,
+            defaultness: None,
+            sig,
+            block: Block { brace_token: Default::default(), stmts: vec![] },
+        })
+    };
+    parser.parse2(tokens).ok()
+}
+
+/// Gives an abstract method declaration a body that calls the inner type's method by the same name
+/// (minus any `_inner_var` suffix), forwarding all the arguments
+fn delegating_fn(mut decl: ImplItemFn, trait_path: Option<&Path>) -> Result<ImplItemFn> {
+    let fn_name = decl.sig.ident.to_string();
+    let fn_ident = Ident::new(fn_name.strip_suffix("_inner_var").unwrap_or(&fn_name), decl.sig.ident.span());
+
+    let mut fn_args = vec![];
+    for arg in decl.sig.inputs.iter() {
+        if let FnArg::Typed(pat) = arg {
+            match ident_for_fn_arg(arg) {
+                Some(ident) => fn_args.push(ident),
+                None => return Err(Error::new(pat.span(), "abstract method arguments must be simple identifiers")),
+            }
         }
     }
-    false
+    let span = decl.sig.span();
+    let self_arg = sig_contains_self_arg(&decl.sig).then(|| quote_spanned!{span => self, });
+
+    let fn_call = match trait_path {
+        Some(trait_path) => quote_spanned!{span => <InnerT as #trait_path>::#fn_ident( #self_arg #(#fn_args),* ) },
+        None if self_arg.is_some() => quote_spanned!{span => self.#fn_ident( #(#fn_args),* ) },
+        None => quote_spanned!{span => InnerT::#fn_ident( #(#fn_args),* ) },
+    };
+
+    //Wrap the result back into the variant if the method returns `Self`.  Any other return type is passed
+    // through as it is, e.g. a method returning `Option<Self>` must already return the sum-type from the inner type
+    let returns_self = matches!(&decl.sig.output, ReturnType::Type(_, ty) if matches!(&**ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("Self")));
+    decl.block = if returns_self {
+        parse2(quote_spanned!{span => { Self::from_inner_var(#fn_call) } })?
+    } else {
+        parse2(quote!{ { #fn_call } })?
+    };
+    Ok(decl)
 }
 
 fn extract_runtime_generic_types(generics: Generics) -> Result<Vec<Ident>> {
@@ -953,7 +2873,7 @@ fn remap_sub_type_fields(src_fields: &[Field], bindings: &[(Ident, Type)], paren
     // convert them back into types.  But I don't want to touch the machinery of replace_idents
     // right now, and also a string is a reasonable lowest-common-denominator
     let parent_enum_type_string = quote!{ #parent_enum_type }.to_string();
-    let stringified_bindings: Vec<(String, String)> = bindings.into_iter()
+    let stringified_bindings: Vec<(String, String)> = bindings.iter()
             .map(|(key, ty)| (key.to_string(), quote!{ #ty }.to_string())).collect();
     let pairs: Vec<(&str, &str)> = stringified_bindings.iter()
             .map(|(key, ty)| (key.as_str(), ty.as_str()))
@@ -962,7 +2882,7 @@ fn remap_sub_type_fields(src_fields: &[Field], bindings: &[(Ident, Type)], paren
                 "InnerT" => (key, "Self"), //Remapping to InnerT should actually remap to the Self
                 _ => (key, ty)
             }})
-            .chain([("Self", parent_enum_type_string.as_str()), ("InnerT", "Self")].into_iter()).collect();
+            .chain([("Self", parent_enum_type_string.as_str()), ("InnerT", "Self")]).collect();
     //QUESTION/ TODO: Should we process "VariantT" here as well?  Probably Yes.
 
     let mut new_fields = vec![];
@@ -1006,16 +2926,6 @@ pub mod generated_example {
     }
 }
 
-//TODO, Make sure overlapping types shared by different variants are handled nicely.
-//Maybe add an attribute so From<> and TryFrom<> impl can be disabled to avoid conflict when two variants have the same type,
-//or at the very least make a nice error
-//UPDATE: any overlap in variant types should probably cause from impls for that type to be
-// disabled totally.  But to get around that problem, I should also make dedicated `from_*t*` constructor methods
-
-//TODO: Need to inject phantom_data into subtypes that don't use all the type_generics.
-//   BETTER IDEA: strip the type-generics for the sub-type.  I should do this after the re-mapping, so I can track which vars are ultimately used, since the re-mapping can map to generic vars
-
-
 //TODO - plan for adding structs 
 // * allow structs to be defined.
 //    - Include a separate block to define:
@@ -0,0 +1,147 @@
+//! Exercises the generated code from a `no_std` crate, to make sure it only depends on `core`
+#![no_std]
+#![allow(clippy::unnecessary_cast, clippy::useless_conversion)]
+
+use summum_types::summum;
+
+summum!{
+    #[summum(kind, views)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Num {
+        F64(f64),
+        I64(i64),
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum NumSlice<'a> {
+        F64(&'a [f64]),
+        I64(&'a [i64]),
+    }
+
+    impl Num {
+        fn max_inner_var() -> Self {
+            InnerT::MAX.into()
+        }
+        fn double(&self) -> Self {
+            (*self * 2 as InnerT).into()
+        }
+        fn add_inner_var(&self, other: InnerT) -> InnerT {
+            *self + other
+        }
+        fn int_only(&self) -> i64 {
+            summum_restrict!(I64);
+            *self
+        }
+        fn name(&self) -> &'static str {
+            summum_variant_name!()
+        }
+    }
+
+    impl<'a> NumSlice<'a> {
+        fn len(&self) -> usize;
+        fn first(&self) -> Option<Num> {
+            self.first().map(|val| Num::from_inner_var(*val))
+        }
+        fn get_inner_var(&self, idx: usize) -> Option<&'a Num::VariantT> {
+            summum_exclude!();
+            self.get(idx)
+        }
+    }
+
+    impl PartialOrd for Num {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            other.try_as_inner_var().and_then(|other| self.partial_cmp(other))
+        }
+    }
+
+    type Meters = f64 | i64 as Count;
+
+    struct Pair variants<T> {
+        Bytes(T=u8),
+        Words(T=u16),
+    } {
+        vals: [T; 2],
+    }
+
+    impl Pair {
+        const LEN: usize = 2;
+        fn sum(&self) -> u32 {
+            self.vals.iter().map(|val| *val as u32).sum()
+        }
+        fn len(&self) -> usize {
+            InnerT::LEN
+        }
+    }
+}
+
+#[test]
+fn accessors() {
+    let mut num = Num::from(1.5);
+    assert!(num.is_f64());
+    assert!(!num.is_i64());
+    assert_eq!(num.try_as_f64(), Some(&1.5));
+    assert_eq!(num.try_as_i64(), None);
+    assert_eq!(num.as_f64(), &1.5);
+    *num.as_mut_f64() = 2.5;
+    assert_eq!(num.try_as_mut_i64(), None);
+    assert_eq!(num.try_into_i64(), Err(NumTryFromError::new(num, "I64")));
+    assert_eq!(num.into_f64(), 2.5);
+    assert_eq!(f64::try_from(num), Ok(2.5));
+    let err = i64::try_from(num).unwrap_err();
+    assert_eq!((err.value, err.expected, err.found), (num, "I64", "F64"));
+    assert_eq!(Num::from_i64(3), Num::I64(3));
+    assert_eq!(num.variant_name(), "F64");
+    assert_eq!(Num::variants(), &["F64", "I64"]);
+    assert_eq!(num.kind(), NumKind::F64);
+    assert_eq!(num.as_view(), NumRef::F64(&2.5));
+    assert_eq!(num.as_view().to_owned_sum(), num);
+    assert_eq!(NumKind::ALL, &[NumKind::F64, NumKind::I64]);
+    assert_eq!("I64".parse::<NumKind>().map(NumKind::name), Ok("I64"));
+
+    assert!(num.is_variant::<num_variants::F64>());
+    assert_eq!(num.try_as_variant::<num_variants::F64>(), Some(&2.5));
+    assert_eq!(num.try_as_mut_variant::<num_variants::I64>(), None);
+    assert_eq!(num.try_into_variant::<num_variants::F64>(), Ok(2.5));
+
+    let _: NumF64T = 1.0;
+    let _: NumSliceI64T = &[1];
+    let _: MetersCountT = Meters::from(1).into_count();
+}
+
+#[test]
+#[should_panic]
+fn accessor_panic() {
+    Num::from(1).into_f64();
+}
+
+#[test]
+fn dispatch() {
+    assert_eq!(Num::max_i64(), Num::I64(i64::MAX));
+    assert_eq!(Num::from(2).double(), Num::I64(4));
+    assert_eq!(Num::from(2.0).add_f64(0.5), 2.5);
+    assert_eq!(Num::from(2).int_only(), 2);
+    assert_eq!(Num::from(2).name(), "I64");
+    assert!(Num::from(2) < Num::from(3));
+    assert_eq!(Num::from(2).partial_cmp(&Num::from(3.0)), None);
+
+    let slice = NumSlice::from(&[1.0, 2.0][..]);
+    assert_eq!(slice.len(), 2);
+    assert_eq!(slice.first(), Some(Num::F64(1.0)));
+    assert_eq!(slice.get_f64(1), Some(&2.0));
+
+    assert_eq!(num_dispatch!(Num::from(3), |inner| inner as f64 / 2 as f64), 1.5);
+}
+
+#[test]
+#[should_panic]
+fn restrict_panic() {
+    Num::from(2.0).int_only();
+}
+
+#[test]
+fn struct_dispatch() {
+    let pair: Pair = PairWords{ vals: [300, 400] }.into();
+    assert!(pair.is_words());
+    assert_eq!(pair.sum(), 700);
+    assert_eq!(pair.len(), Pair::LEN);
+}
@@ -1,4 +1,9 @@
 
+//Method bodies get expanded once per variant, so casts and conversions that are needed by one
+// variant are redundant for another
+#![allow(clippy::unnecessary_cast, clippy::useless_conversion, clippy::unit_arg)]
+
+use std::collections::{HashMap, VecDeque};
 
 use summum_types::summum;
 
@@ -110,6 +115,12 @@ summum!{
         fn get_inner_var(&self, idx: usize) -> Option<Num> {
             self.get(idx).map(|r| (*r).into())
         }
+        fn push_inner_var(&mut self, item: Num::VariantT) {
+            self.push(item)
+        }
+        fn get_or_default(&self, idx: usize) -> Num {
+            self.get(idx).cloned().unwrap_or(Num::VariantT::default()).into()
+        }
     }
 }
 
@@ -123,6 +134,83 @@ fn cross_type_interop() {
     assert_eq!(vec.get_i64(0).unwrap().into_i64(), 42);
 }
 
+#[test]
+fn variant_type_aliases() {
+    let val: NumI64T = 42;
+    assert_eq!(Num::from(val).into_i64(), 42);
+    let slice: SliceOrPieSliceT<i64> = &[1, 2];
+    let vec: SliceOrPieVecT<i64> = vec![1, 2];
+    assert_eq!(SliceOrPie::from(slice).get(1), SliceOrPie::from(vec).get(1));
+    let _: VecOrVVT<u8> = 0u8;
+    let _: HaskellNestedOrNotRefVT<u8> = &0u8;
+}
+
+summum!{
+    #[derive(Debug, PartialEq)]
+    enum Node {
+        Variant(u8),
+        Other(i64),
+    }
+}
+
+#[test]
+fn variant_marker_accessors() {
+    let mut num = Num::from(42);
+    assert!(num.is_variant::<num_variants::I64>());
+    assert!(!num.is_variant::<num_variants::F64>());
+    assert_eq!(num.try_as_variant::<num_variants::I64>(), Some(&42));
+    *num.try_as_mut_variant::<num_variants::I64>().unwrap() += 1;
+    let err = num.try_into_variant::<num_variants::F64>().unwrap_err();
+    assert_eq!((err.expected, err.found), ("F64", "I64"));
+    assert_eq!(err.value.try_into_variant::<num_variants::I64>().unwrap(), 43);
+    let val: i64 = Num::from(44).try_into().unwrap();
+    assert_eq!(val, 44);
+
+    let node = Node::from(3u8);
+    assert!(node.is_variant());
+    assert_eq!(node.try_as_variant(), Some(&3));
+    assert!(!node.is_other());
+    assert_eq!(<node_variants::Variant as node_variants::traits::Variant<Node>>::NAME, "Variant");
+
+    fn describe<S, M: vec_or_v_variants::traits::Variant<S>>(sum: &S) -> &'static str {
+        M::try_as(sum).map(|_| M::NAME).unwrap_or("other")
+    }
+    let sum: VecOrV<u8> = 7.into();
+    assert_eq!(describe::<_, vec_or_v_variants::V>(&sum), "V");
+    assert_eq!(describe::<_, vec_or_v_variants::Vec>(&sum), "other");
+}
+
+summum!{
+    #[derive(Debug, PartialEq)]
+    enum Length {
+        Meters(f64),
+        Feet(f64),
+        Count(u32),
+    }
+
+    impl Length {
+        fn double(&self) -> Self {
+            Self::from_inner_var(*self * 2 as InnerT)
+        }
+    }
+}
+
+#[test]
+fn overlapping_inner_types() {
+    assert_eq!(Length::from_feet(1.5).double(), Length::from_feet(3.0));
+    assert_eq!(Length::from_meters(1.5).double().into_meters(), 3.0);
+    assert_eq!(Length::from(2).double(), Length::Count(4));
+    assert_eq!(u32::try_from(Length::Count(2)), Ok(2));
+}
+
+#[test]
+fn cross_type_variant_t() {
+    let mut vec: NumVec = Vec::<f64>::new().into();
+    vec.push_f64(1.5);
+    assert_eq!(vec.get_or_default(0).into_f64(), 1.5);
+    assert_eq!(vec.get_or_default(1).into_f64(), 0.0);
+}
+
 summum!{
     #[derive(Debug, PartialEq)]
     enum NumAgain {
@@ -209,59 +297,1006 @@ fn validate_sub_type_structures() {
     };
 }
 
+summum!{
+    #[derive(Debug, Clone)]
+    enum Shape {
+        Circle(f64),
+        Square(i64),
+    }
+
+    impl core::fmt::Display for Shape {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "{}({})", summum_variant_name!(), self)
+        }
+    }
+
+    impl Halve for Shape {
+        fn halve(&self) -> Self {
+            if *self == 0 as InnerT {
+                summum_restrict!(Square);
+                Self::zero_inner_var()
+            } else {
+                (*self / 2 as InnerT).into()
+            }
+        }
+    }
+
+    impl Shape {
+        fn zero_inner_var() -> Self {
+            (0 as InnerT).into()
+        }
+    }
+
+    impl Default for Shape {
+        fn default() -> Self {
+            Self::zero_square()
+        }
+    }
+
+    impl core::str::FromStr for Shape {
+        type Err = core::num::ParseFloatError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.parse::<i64>() {
+                Ok(val) => Ok(val.into()),
+                Err(_) => s.parse::<f64>().map(Self::from),
+            }
+        }
+    }
+}
+
+trait Halve {
+    fn halve(&self) -> Self;
+}
+
+#[test]
+fn trait_impls() {
+    assert_eq!(Shape::from(3.0).to_string(), "Circle(3)");
+    assert_eq!(Shape::from(7).to_string(), "Square(7)");
+    assert_eq!(Shape::from(3.0).halve().into_circle(), 1.5);
+    assert_eq!(Shape::from(7).halve().into_square(), 3);
+    assert_eq!(Shape::from(0).halve().into_square(), 0);
+    assert_eq!(Shape::default().into_square(), 0);
+    assert_eq!("2.5".parse::<Shape>().unwrap().into_circle(), 2.5);
+    assert_eq!("4".parse::<Shape>().unwrap().into_square(), 4);
+}
+
+summum!{
+    struct Counter variants<T> {
+        Up(T=u32),
+        Down(T=u8),
+    } {
+        remaining: T,
+    }
+
+    impl Iterator for Counter {
+        type Item = u32;
+        fn next(&mut self) -> Option<u32> {
+            if self.remaining == 0 {
+                None
+            } else {
+                self.remaining -= 1;
+                Some(self.remaining as u32)
+            }
+        }
+    }
+}
+
+#[test]
+fn trait_impls_on_struct_sum_types() {
+    let counter: Counter = CounterUp{ remaining: 3 }.into();
+    assert_eq!(counter.collect::<Vec<_>>(), vec![2, 1, 0]);
+    let counter: Counter = CounterDown{ remaining: 2 }.into();
+    assert_eq!(counter.count(), 2);
+}
+
+summum!{
+    #[derive(Clone)]
+    struct Holder<V> variants<T> {
+        Generic(T=V),
+        Concrete(T=i64),
+    } {
+        val: T,
+        count: usize,
+    }
+
+    impl<V> Holder<V> where V: Clone {
+        const LABEL: &'static str = "holder";
+        const SIZE: usize = core::mem::size_of::<InnerT>();
+        fn count(&self) -> usize {
+            self.count
+        }
+        fn label(&self) -> &'static str {
+            InnerT::LABEL
+        }
+        fn size(&self) -> usize {
+            InnerT::SIZE
+        }
+        fn take_val_inner_var(self) -> InnerT {
+            self
+        }
+    }
+}
+
+summum!{
+    struct Label<'a> variants<T> {
+        Borrowed(T=&'a str),
+        Owned(T=u32),
+    } {
+        text: T,
+    }
+
+    impl<'a> Label<'a> {
+        fn doubled_size(&self) -> usize {
+            let a = 2;
+            core::mem::size_of::<InnerT>() * a
+        }
+    }
+}
+
+#[test]
+fn sub_types_strip_unused_generics() {
+    let concrete = HolderConcrete{ val: 42, count: 1 };
+    let holder: Holder<String> = concrete.into();
+    assert_eq!(holder.count(), 1);
+    assert_eq!(holder.label(), "holder");
+    assert_eq!(holder.size(), core::mem::size_of::<HolderConcrete>());
+    assert_eq!(holder.take_val_concrete().val, 42);
+    let holder: Holder<String> = HolderGeneric{ val: "forty-two".to_string(), count: 2 }.into();
+    assert_eq!(holder.clone().count(), 2);
+    assert_eq!(Holder::<String>::LABEL, "holder");
+    assert_eq!(holder.take_val_generic().val, "forty-two");
+    let label = Label::from(LabelOwned{ text: 7 });
+    assert_eq!(label.doubled_size(), 2 * core::mem::size_of::<LabelOwned>());
+    assert_eq!(label.into_owned().text, 7);
+    let label = Label::from(LabelBorrowed{ text: "abc" });
+    assert_eq!(label.doubled_size(), 2 * core::mem::size_of::<LabelBorrowed>());
+    assert_eq!(label.into_borrowed().text, "abc");
+}
+
+summum!{
+    #[derive(Debug, Clone)]
+    enum Collection<T> {
+        Vec(Vec<T>),
+        Deque(VecDeque<T>),
+    }
+
+    impl<T> Collection<T> {
+        pub fn len(&self) -> usize;
+        pub fn is_empty(&self) -> bool;
+        fn clear(&mut self);
+        fn new_inner_var() -> Self;
+    }
+
+    impl<T> Extend<T> for Collection<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I);
+    }
+}
+
+#[test]
+fn abstract_method_declarations() {
+    let mut collection: Collection<i64> = Collection::new_deque();
+    assert!(collection.is_deque());
+    assert!(collection.is_empty());
+    collection.extend([1, 2, 3]);
+    assert_eq!(collection.len(), 3);
+    collection.clear();
+    assert!(collection.is_empty());
+    assert_eq!(Collection::<i64>::new_vec().len(), 0);
+}
+
+summum!{
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Len {
+        Meters(f64),
+        Feet(f64),
+    }
+
+    impl Len {
+        fn abs(self) -> Self;
+        fn is_sign_negative(self) -> bool;
+    }
+}
+
+pub struct Draft;
+pub struct Published;
+
+impl Draft {
+    fn advance(&self) -> Option<Stage> {
+        Some(Published.into())
+    }
+}
+
+impl Published {
+    fn advance(&self) -> Option<Stage> {
+        None
+    }
+}
+
+summum!{
+    enum Stage {
+        Draft(Draft),
+        Published(Published),
+    }
+
+    impl Stage {
+        fn advance(&self) -> Option<Self>;
+    }
+}
+
+#[test]
+fn abstract_methods_returning_self() {
+    let len = Len::from_feet(-3.0).abs();
+    assert_eq!(len, Len::Feet(3.0));
+    assert!(!len.is_sign_negative());
+    assert_eq!(Len::from_meters(-1.5).abs(), Len::Meters(1.5));
+
+    let stage = Stage::from(Draft).advance().unwrap();
+    assert!(stage.is_published());
+    assert!(stage.advance().is_none());
+}
+
+mod attr_types {
+    use summum_types::{summum, summum_type};
+
+    #[summum_type]
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Temperature {
+        Celsius(f64),
+        Kelvin(u32),
+    }
+
+    summum!{
+        #[derive(Debug)]
+        pub struct Reading variants<T> {
+            Precise(T=f64),
+            Rough(T=i8),
+        } {
+            pub val: T,
+        }
+    }
+}
+
+mod attr_impls {
+    use summum_types::summum_impl;
+    use super::attr_types::*;
+
+    summum_impl!{
+        impl Temperature {
+            pub fn describe(&self) -> String {
+                format!("{} {}", self, summum_variant_name!())
+            }
+            pub fn zero_inner_var() -> Self {
+                (0 as InnerT).into()
+            }
+        }
+
+        impl Reading {
+            pub fn val(&self) -> f64 {
+                self.val as f64
+            }
+        }
+    }
+}
+
+mod attr_trait_impls {
+    use summum_types::summum_impl;
+
+    summum_impl!{
+        impl core::fmt::Display for crate::attr_types::Temperature {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+        }
+    }
+}
+
+#[test]
+fn attribute_macro_and_separate_impls() {
+    use attr_types::*;
+    assert_eq!(Temperature::from(21.5).describe(), "21.5 Celsius");
+    assert_eq!(Temperature::zero_kelvin().into_kelvin(), 0);
+    assert_eq!(Temperature::variants(), &["Celsius", "Kelvin"]);
+    let reading: Reading = ReadingRough{ val: -3 }.into();
+    assert_eq!(reading.val(), -3.0);
+}
+
+mod derived {
+    use summum_types::{summum_impl, SummumAccessors};
+
+    /// An enum that's defined outside of any summum macro
+    #[derive(Debug, Clone, PartialEq, SummumAccessors)]
+    pub enum Token<'a, T> {
+        Word(&'a str),
+        Values(Vec<T>),
+    }
+
+    summum_impl!{
+        impl<'a, T: core::fmt::Debug> Token<'a, T> {
+            pub fn debug_string(&self) -> String {
+                format!("{:?}", self)
+            }
+        }
+    }
+
+    /// A hand-written kind enum, which doesn't collide with anything because `#[summum(kind)]` wasn't given
+    #[derive(Debug, PartialEq)]
+    pub enum TokenKind {
+        Text,
+        List,
+    }
+
+    impl<T> Token<'_, T> {
+        pub fn kind(&self) -> TokenKind {
+            match self {
+                Self::Word(_) => TokenKind::Text,
+                Self::Values(_) => TokenKind::List,
+            }
+        }
+    }
+}
+
+#[test]
+fn derived_accessors() {
+    use derived::*;
+    let word: Token<i32> = Token::from("hello");
+    assert!(word.is_word());
+    assert_eq!(word.as_word(), &"hello");
+    assert_eq!(word.variant_name(), "Word");
+    assert_eq!(Token::<i32>::variants(), &["Word", "Values"]);
+    assert_eq!(word.debug_string(), "\"hello\"");
+    assert_eq!(word.kind(), TokenKind::Text);
+
+    let values = Token::from(vec![1, 2]);
+    assert_eq!(values.try_as_word(), None);
+    assert_eq!(values.try_as_variant::<token_variants::Values>(), Some(&vec![1, 2]));
+    assert_eq!(values.clone().into_values(), vec![1, 2]);
+    assert_eq!(values.debug_string(), "[1, 2]");
+}
+
+/// A free function that's generic over the variants of `NumAgain`, outside of any impl block
+fn halve_num(num: NumAgain) -> NumAgain {
+    num_again_dispatch!(num, |inner| (inner / 2 as InnerT).into())
+}
+
+#[test]
+fn dispatch_macro() {
+    assert_eq!(halve_num(3.0.into()), NumAgain::F64(1.5));
+    assert_eq!(halve_num(3.into()), NumAgain::I64(1));
+
+    let mut num = NumAgain::from(7);
+    num_again_dispatch!(&mut num, |inner| *inner += 1 as InnerT);
+    assert_eq!(num, NumAgain::I64(8));
+
+    let name = num_again_dispatch!(&num, |_| {
+        summum_restrict!(I64);
+        summum_variant_name!()
+    });
+    assert_eq!(name, "I64");
+
+    use attr_types::*;
+    let temperature = Temperature::from(300);
+    assert!(temperature_dispatch!(temperature, |inner| Temperature::zero_inner_var().into_inner_var() < inner));
+    assert_eq!(reading_dispatch!(Reading::from(ReadingPrecise{ val: 0.5 }), |inner| inner.val as f64), 0.5);
+}
+
+mod exported {
+    use summum_types::summum;
+    summum!{
+        #[summum(export_dispatch)]
+        #[derive(Debug, PartialEq)]
+        pub enum Measure {
+            U8(u8),
+            F32(f32),
+        }
+    }
+}
+
+#[test]
+fn exported_dispatch_macro() {
+    use exported::Measure;
+    let doubled = exported::measure_dispatch!(Measure::from(4u8), |inner| Measure::from(inner * 2 as InnerT));
+    assert_eq!(doubled, Measure::U8(8));
+    assert_eq!(crate::exported::measure_dispatch!(Measure::from(1.5f32), |inner| inner as f64), 1.5);
+}
+
 
+summum!{
+    #[derive(Debug, Clone, PartialEq)]
+    enum Shape2D {
+        Circle(f64),
+        Rect(f64, f64),
+        Ellipse{ rx: f32, ry: f32 },
+    }
 
+    impl Shape2D {
+        fn area(&self) -> f64 {
+            summum_exclude!(Rect, Ellipse);
+            std::f64::consts::PI * self * self
+        }
+        fn scale(&mut self, factor: f64) {
+            summum_restrict!(Rect);
+            *self.0 *= factor;
+            *self.1 *= factor;
+        }
+        fn unit_inner_var() -> Self {
+            InnerT::default().into()
+        }
+    }
+}
 
+#[test]
+fn multi_field_variants() {
+    let mut rect = Shape2D::from((2.0, 3.0));
+    assert_eq!(rect, Shape2D::Rect(2.0, 3.0));
+    assert_eq!(rect.as_rect(), (&2.0, &3.0));
+    rect.scale(2.0);
+    assert_eq!(rect.try_as_rect(), Some((&4.0, &6.0)));
+    *rect.as_mut_rect().1 = 1.0;
+    assert_eq!(rect.try_as_variant::<shape2_d_variants::Rect>(), Some((&4.0, &1.0)));
+    assert_eq!(rect.clone().into_rect(), (4.0, 1.0));
+    assert_eq!(<(f64, f64)>::try_from(rect), Ok((4.0, 1.0)));
 
+    let ellipse = Shape2D::from_ellipse((1.0, 2.0));
+    assert_eq!(ellipse, Shape2D::Ellipse{ rx: 1.0, ry: 2.0 });
+    assert_eq!(ellipse.variant_name(), "Ellipse");
+    assert_eq!(ellipse.try_as_circle(), None);
+    assert_eq!(ellipse.into_ellipse(), (1.0, 2.0));
+    assert_eq!(Shape2D::unit_ellipse(), Shape2D::Ellipse{ rx: 0.0, ry: 0.0 });
+    assert_eq!(Shape2D::from(1.0).area(), std::f64::consts::PI);
 
+    let _: Shape2DRectT = (1.0, 1.0);
+    assert_eq!(shape2_d_dispatch!(Shape2D::Rect(1.0, 2.0), |inner| {
+        summum_restrict!(Rect);
+        inner.0
+    }), 1.0);
+}
 
+summum!{
+    #[summum(kind, views)]
+    #[derive(Debug, Clone, PartialEq)]
+    enum Value {
+        Null,
+        Int(i64),
+        Str(String),
+    }
 
+    impl Value {
+        fn render(&self) -> String {
+            if summum_variant_name!() == "Null" {
+                return "null".to_string();
+            }
+            summum_exclude!(Null);
+            self.to_string()
+        }
+        fn default_inner_var() -> Self {
+            InnerT::default().into()
+        }
+    }
+}
 
+#[test]
+fn unit_variants() {
+    let null = Value::Null;
+    assert!(null.is_null());
+    assert!(!Value::from(1).is_null());
+    assert_eq!(null.try_as_null(), Some(()));
+    assert_eq!(Value::from(1).try_as_null(), None);
+    assert_eq!(null.clone().try_into_null(), Ok(()));
+    assert_eq!(null.variant_name(), "Null");
+    assert_eq!(Value::variants(), &["Null", "Int", "Str"]);
+    assert_eq!(Value::from(()), Value::Null);
+    assert_eq!(Value::from_null(()), Value::Null);
+    assert_eq!(<()>::try_from(Value::Null), Ok(()));
+    assert!(Value::Null.is_variant::<value_variants::Null>());
 
+    assert_eq!(null.render(), "null");
+    assert_eq!(Value::from(5).render(), "5");
+    assert_eq!(Value::default_null(), Value::Null);
+    assert_eq!(Value::default_str(), Value::Str(String::new()));
+    let _: ValueNullT = ();
+}
 
+summum!{
+    #[summum(kind)]
+    #[derive(Debug, Clone, PartialEq)]
+    enum Configured {
+        Int(i64),
+        #[cfg(any())]
+        Missing(NoSuchType),
+        #[cfg_attr(any(), cfg(any()))]
+        Float(f64),
+    }
 
+    impl Configured {
+        fn double(&self) -> Self {
+            (*self * 2 as InnerT).into()
+        }
+        fn zero_inner_var() -> Self {
+            (0 as InnerT).into()
+        }
+    }
 
+    struct ConfiguredPair variants<T> {
+        Present(T=u8),
+        #[cfg(any())]
+        Absent(T=NoSuchType),
+    } {
+        vals: [T; 2],
+    }
 
+    impl ConfiguredPair {
+        fn first(&self) -> u8 {
+            self.vals[0] as u8
+        }
+    }
+}
 
+#[test]
+fn cfg_variants() {
+    assert_eq!(Configured::variants(), &["Int", "Float"]);
+    assert_eq!(Configured::from(2).double(), Configured::Int(4));
+    assert_eq!(Configured::zero_float().variant_name(), "Float");
+    assert_eq!(configured_dispatch!(Configured::from(1.5), |inner| inner as f64), 1.5);
+    assert_eq!(ConfiguredPair::variants(), &["Present"]);
+    assert_eq!(ConfiguredPair::from(ConfiguredPairPresent{ vals: [1, 2] }).first(), 1);
+}
 
-// use std::collections::HashMap;
-// use std::sync::Arc;
+#[test]
+fn try_from_error() {
+    fn int_value(val: Value) -> Result<i64, Box<dyn std::error::Error>> {
+        Ok(i64::try_from(val)?)
+    }
+    assert_eq!(int_value(Value::from(3)).unwrap(), 3);
+    let err = int_value(Value::Null).unwrap_err();
+    assert_eq!(err.to_string(), "expected Value::Int, found Value::Null");
 
-// use expr_map::Expr;
+    let err = Value::from("hello".to_string()).try_into_int().unwrap_err();
+    assert_eq!(format!("{err:?}"), r#"ValueTryFromError { expected: "Int", found: "Str", .. }"#);
+    assert_eq!(err.clone().value.into_str(), "hello");
+    assert_eq!(err, ValueTryFromError::new(Value::Str("hello".to_string()), "Int"));
+}
 
-// /// The ExprMap object, stores expressions that can be used as keys to retrieve values
-// #[derive(Clone)]
-// pub struct ExprMap<V> {
-//     apps: Option<Arc<Self>>,
-//     vars: NestedOrNotMap<V>,
-// }
+#[test]
+fn kind_enum() {
+    use std::collections::HashMap;
 
-// summum!{
-//     /// Private type to help untangle an ExprMap<ExprMap<V>> with Rust's static type system
-//     #[derive(Clone)]
-//     enum NestedOrNotMap<V> {
-//         Nested(HashMap<usize, ExprMap<V>>),
-//         Not(HashMap<usize, V>),
-//     }
+    assert_eq!(Value::from(3).kind(), ValueKind::Int);
+    assert_eq!(Value::Null.kind().name(), "Null");
+    assert_eq!(ValueKind::ALL, &[ValueKind::Null, ValueKind::Int, ValueKind::Str]);
+    assert!(ValueKind::Null < ValueKind::Str);
+    assert_eq!("Str".parse::<ValueKind>(), Ok(ValueKind::Str));
+    assert_eq!("str".parse::<ValueKind>(), Err(ValueKindParseError));
+    assert_eq!(ValueKindParseError.to_string(), "unknown variant of Value");
+    assert_eq!(ConfiguredKind::ALL, &[ConfiguredKind::Int, ConfiguredKind::Float]);
 
-//     impl<V> NestedOrNotMap<V> {
-//         pub fn get(&self, idx: &usize) -> Option<NestedOrNotRef<V>> {
-//             self.get(idx).map(|r| r.into())
-//         }
-//         pub fn get_mut(&mut self, idx: &usize) -> Option<NestedOrNotRefMut<V>> {
-//             self.get_mut(idx).map(|r| r.into())
-//         }
-//         pub fn insert(&mut self, idx: usize, val: NestedOrNot<V>) {
-//             self.insert(idx, val.into_inner_var());
-//         }
-//     }
+    let mut counts = HashMap::new();
+    for val in [Value::Null, Value::from(1), Value::from(2)] {
+        *counts.entry(val.kind()).or_insert(0) += 1;
+    }
+    assert_eq!(counts[&ValueKind::Int], 2);
+}
 
-//     #[derive(Clone)]
-//     type NestedOrNot<V> = ExprMap<V> as Nested | V as Not;
+/// The ExprMap object, stores values by index, including nested ExprMaps
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExprMap<V> {
+    vars: NestedOrNotMap<V>,
+}
 
-//     #[derive(Clone)]
-//     type NestedOrNotRef<'a, V> = &'a ExprMap<V> as Nested | &'a V as Not;
+summum!{
+    /// Private type to help untangle an ExprMap<ExprMap<V>> with Rust's static type system
+    #[derive(Clone, Debug, PartialEq)]
+    enum NestedOrNotMap<V> {
+        Nested(HashMap<usize, ExprMap<V>>),
+        Not(HashMap<usize, V>),
+    }
 
-//     type NestedOrNotRefMut<'a, V> = &'a mut ExprMap<V> as Nested | &'a mut V as Not;
+    impl<V> NestedOrNotMap<V> {
+        pub fn get(&self, idx: &usize) -> Option<NestedOrNotRef<'_, V>> {
+            self.get(idx).map(|r| r.into())
+        }
+        pub fn get_mut(&mut self, idx: &usize) -> Option<NestedOrNotMut<'_, V>> {
+            self.get_mut(idx).map(|r| r.into())
+        }
+        pub fn insert(&mut self, idx: usize, val: NestedOrNot<V>) {
+            self.insert(idx, val.into_inner_var());
+        }
+    }
+
+    #[summum(views)]
+    #[derive(Clone, Debug, PartialEq)]
+    type NestedOrNot<V> = ExprMap<V> as Nested | V as Not;
+}
+
+#[test]
+fn borrowed_views() {
+    let mut map = NestedOrNotMap::from(HashMap::<usize, i64>::new());
+    map.insert(0, 5.into());
+    assert_eq!(map.get(&0), Some(NestedOrNotRef::Not(&5)));
+    *map.get_mut(&0).unwrap().into_not() += 1;
+    let val = map.get(&0).unwrap();
+    let copied = val;
+    assert_eq!(val.to_owned_sum(), NestedOrNot::Not(6));
+    assert_eq!(copied.as_not(), &&6);
+
+    let mut owned = NestedOrNot::<i64>::from(ExprMap{ vars: map });
+    assert!(owned.as_view().is_nested());
+    owned.as_mut_view().as_mut_nested().vars = NestedOrNotMap::from(HashMap::<usize, i64>::new());
+    assert_eq!(owned.as_mut_view().to_owned_sum(), owned);
+    assert_eq!(owned.as_view().variant_name(), "Nested");
+
+    let mut value = Value::Str("hi".to_string());
+    value.as_mut_view().as_mut_str().push('!');
+    assert_eq!(value.as_view().to_owned_sum(), Value::Str("hi!".to_string()));
+    assert_eq!(Value::Null.as_view(), ValueRef::Null);
+    assert_eq!(value.as_view().kind(), value.kind());
+    assert_eq!(value.as_mut_view().kind(), ValueKind::Str);
+    assert_eq!(value.as_view().try_into_int(), Err(value.as_view()));
+
+    //The std traits aren't shadowed by the views' methods
+    let value_ref = value.as_view();
+    assert_eq!(value_ref.to_owned(), value_ref);
+}
+
+summum!{
+    /// The variant accessors `as_view` and `as_mut_view` take the place of the view methods
+    #[summum(views)]
+    #[derive(Debug, Clone, PartialEq)]
+    enum Access {
+        View(u8),
+        Mut(u16),
+    }
+}
+
+summum!{
+    /// Without `#[summum(views)]`, the view names are free for other types
+    #[derive(Debug, PartialEq)]
+    enum Bare {
+        Int(i64),
+        Text(String),
+    }
+}
+
+struct BareRef<'a>(&'a Bare);
+
+impl AsRef<Access> for Access {
+    fn as_ref(&self) -> &Access {
+        self
+    }
+}
+
+#[test]
+fn views_with_clashing_variant_names() {
+    let mut access = Access::from(1u8);
+    assert_eq!(access.try_as_view(), Some(&1));
+    assert_eq!(access.as_view(), &1);
+    *access.as_mut_view() += 1;
+    assert_eq!(access, Access::View(2));
+    assert_eq!(AccessRef::from(&4u16).to_owned_sum(), Access::Mut(4));
+    assert_eq!(Access::from(3u16).try_as_mut_view(), None);
+    assert_eq!(access.as_ref(), &access);
+    assert_eq!(BareRef(&Bare::from(1)).0, &Bare::Int(1));
+}
+
+summum!{
+    #[summum(conversions)]
+    #[derive(Debug, Clone, PartialEq)]
+    enum Number {
+        Int(i64),
+        Float(f64),
+    }
+
+    #[summum(conversions)]
+    #[derive(Debug, Clone, PartialEq)]
+    enum Literal {
+        Int(i64),
+        Float(f64),
+        Text(String),
+        Flag(bool),
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Numeric {
+        Float(f64),
+        Int(i64),
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Flags {
+        Flag(u8),
+    }
+
+    /// Neither `Count` nor `Numeric` asks for conversions, so they can be written by hand
+    #[derive(Debug, Clone, PartialEq)]
+    enum Count {
+        Int(i64),
+    }
+}
+
+impl From<Count> for Numeric {
+    fn from(count: Count) -> Self {
+        Numeric::Float(count.into_int() as f64)
+    }
+}
+
+#[test]
+fn widening_and_narrowing() {
+    assert_eq!(Literal::from(Number::Int(3)), Literal::Int(3));
+    assert_eq!(Number::try_from(Literal::Float(1.5)), Ok(Number::Float(1.5)));
+    assert_eq!(Number::try_from(Literal::from(true)), Err(Literal::Flag(true)));
 
-// }
+    //Types with the same variants convert both ways
+    assert_eq!(Numeric::from(Number::Int(2)), Numeric::Int(2));
+    assert_eq!(Number::from(Numeric::Float(0.5)), Number::Float(0.5));
+    assert_eq!(Literal::from(Numeric::Int(1)), Literal::Int(1));
+
+    //`Flags::Flag` holds a different type than `Literal::Flag`, so there's no conversion
+    assert_eq!(Flags::from(1u8).variant_name(), Literal::Flag(false).variant_name());
+
+    assert_eq!(Numeric::from(Count::Int(4)), Numeric::Float(4.0));
+}
+
+summum!{
+    #[summum(views)]
+    #[derive(Debug, Clone, PartialEq)]
+    type Scalar = f64 | i64;
+
+    #[summum(views)]
+    #[derive(Debug, Clone, PartialEq)]
+    type Atom = ..Scalar | bool;
+
+    #[derive(Debug, Clone, PartialEq)]
+    type Datum = String | ..Atom | Vec<u8> as Bytes;
+}
+
+#[test]
+fn spread_variants() {
+    assert_eq!(Datum::variants(), &["String", "F64", "I64", "Bool", "Bytes"]);
+    let datum = Datum::from(Scalar::from(2));
+    assert_eq!(datum, Datum::I64(2));
+    assert!(datum.is_scalar());
+    assert!(datum.is_atom());
+    assert!(!Datum::from(true).is_scalar());
+    assert_eq!(datum.try_as_scalar(), Some(ScalarRef::I64(&2)));
+    assert_eq!(Datum::from(1.5).try_into_atom(), Ok(Atom::F64(1.5)));
+    let err = Datum::from("a".to_string()).try_into_scalar().unwrap_err();
+    assert_eq!((err.value, err.expected, err.found), (Datum::String("a".to_string()), "Scalar", "String"));
+    assert_eq!(Scalar::try_from(Atom::from(0.5)), Ok(Scalar::F64(0.5)));
+
+    let mut datum = Datum::from(Atom::Bool(false));
+    *datum.try_as_mut_atom().unwrap().into_bool() = true;
+    assert_eq!(datum, Datum::Bool(true));
+}
+
+summum!{
+    #[summum(delegate(Display, LowerHex), transparent_debug, views)]
+    #[derive(Clone, PartialEq)]
+    enum Register {
+        Byte(u8),
+        Word(u32),
+    }
+
+    #[summum(transparent_debug)]
+    enum Slot<T> {
+        Empty,
+        Items(Vec<T>),
+        Pair(T, u8),
+    }
+}
+
+#[test]
+fn delegated_formatting() {
+    let reg = Register::from(255u8);
+    assert_eq!(reg.to_string(), "255");
+    assert_eq!(format!("{reg:x}"), "ff");
+    assert_eq!(format!("{:#06x}", Register::from(42u32)), "0x002a");
+    assert_eq!(format!("{reg:?}"), "255");
+    assert_eq!(format!("{} {:?}", reg.as_view(), reg.as_view()), "255 255");
+
+    assert_eq!(format!("{:?}", Slot::<&str>::Empty), "Empty");
+    assert_eq!(format!("{:?}", Slot::Items(vec!["a"])), r#"["a"]"#);
+    assert_eq!(format!("{:?}", Slot::Pair(-1, 2)), "(-1, 2)");
+}
+
+type Doubled = std::iter::Map<std::ops::Range<u32>, fn(u32) -> u32>;
+
+summum!{
+    #[summum(delegate(Iterator))]
+    enum Evens<'a> {
+        Slice(std::iter::Copied<std::slice::Iter<'a, u32>>),
+        Range(std::ops::Range<u32>),
+        Mapped(Doubled),
+    }
+
+    #[summum(delegate(Iterator))]
+    enum Chars<'a> {
+        Chars(std::str::Chars<'a>),
+        Repeat(std::iter::RepeatN<char>),
+    }
+}
+
+fn evens(slice: &[u32]) -> Evens<'_> {
+    if slice.is_empty() {
+        Evens::from(0..4)
+    } else {
+        Evens::from(slice.iter().copied())
+    }
+}
+
+#[test]
+fn iterator_delegation() {
+    assert_eq!(evens(&[]).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    assert_eq!(evens(&[5, 6]).rev().collect::<Vec<_>>(), vec![6, 5]);
+    assert_eq!(evens(&[5, 6, 7]).len(), 3);
+    assert_eq!(evens(&[]).size_hint(), (4, Some(4)));
+    assert_eq!(evens(&[]).nth(2), Some(2));
+    assert_eq!(evens(&[1, 2]).fold(String::new(), |s, x| s + &x.to_string()), "12");
+    let mapped: Evens = (1..4).map((|x| x * 2) as fn(u32) -> u32).into();
+    assert_eq!(mapped.fuse().last(), Some(6));
+
+    //`std::str::Chars` isn't an ExactSizeIterator, but `Chars` is still an Iterator
+    let chars = Chars::from("hi".chars()).chain(Chars::from(std::iter::repeat_n('!', 2)));
+    assert_eq!(chars.collect::<String>(), "hi!!");
+}
+
+summum!{
+    #[summum(delegate(Read, BufRead))]
+    enum Input<'a> {
+        Bytes(&'a [u8]),
+        Cursor(std::io::Cursor<Vec<u8>>),
+    }
+
+    #[summum(delegate(Write, Seek))]
+    enum Output {
+        Growable(std::io::Cursor<Vec<u8>>),
+        Fixed(std::io::Cursor<[u8; 8]>),
+    }
+}
+
+#[test]
+fn io_delegation() {
+    use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+
+    let mut input = Input::from(&b"zero\none\ntwo"[..]);
+    assert_eq!(input.skip_until(b'\n').unwrap(), 5);
+    let mut line = String::new();
+    input.read_line(&mut line).unwrap();
+    assert_eq!(line, "one\n");
+    let mut rest = vec![];
+    input.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, b"two");
+    let input = Input::from(std::io::Cursor::new(b"a\nb".to_vec()));
+    assert_eq!(input.lines().map(Result::unwrap).collect::<Vec<_>>(), vec!["a", "b"]);
+
+    let mut output = Output::from(std::io::Cursor::new([0u8; 8]));
+    write!(output, "abc").unwrap();
+    output.seek(SeekFrom::Start(1)).unwrap();
+    output.write_all(b"X").unwrap();
+    assert_eq!(output.stream_position().unwrap(), 2);
+    output.seek_relative(-2).unwrap();
+    assert_eq!(output.stream_position().unwrap(), 0);
+    assert_eq!(&output.into_fixed().get_ref()[..3], b"aXc");
+
+    let mut output = Output::from(std::io::Cursor::new(vec![]));
+    output.write_all(b"hello").unwrap();
+    output.rewind().unwrap();
+    output.flush().unwrap();
+    assert_eq!(output.as_growable().get_ref(), b"hello");
+}
+
+/// A future that isn't `Unpin`, which is ready after it's been polled `remaining` more times
+pub struct Countdown {
+    remaining: std::cell::Cell<u32>,
+    _pinned: std::marker::PhantomPinned,
+}
+
+impl std::future::Future for Countdown {
+    type Output = &'static str;
+    fn poll(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {
+        match self.remaining.get() {
+            0 => std::task::Poll::Ready("done"),
+            remaining => {
+                self.remaining.set(remaining - 1);
+                cx.waker().wake_by_ref();
+                std::task::Poll::Pending
+            }
+        }
+    }
+}
+
+summum!{
+    #[summum(delegate(Future))]
+    enum Job {
+        Ready(std::future::Ready<&'static str>),
+        Countdown(Countdown),
+    }
+}
+
+/// Only compiles if `T` isn't `Unpin`, because otherwise both impls apply and `A` is ambiguous
+trait AmbiguousIfUnpin<A> {
+    fn check() {}
+}
+impl<T: ?Sized> AmbiguousIfUnpin<()> for T {}
+impl<T: ?Sized + Unpin> AmbiguousIfUnpin<u8> for T {}
+
+fn assert_unpin<T: Unpin>() {}
+
+summum!{
+    #[summum(delegate(Future))]
+    enum Timeout {
+        Elapsed(std::future::Ready<()>),
+        Never(std::future::Pending<()>),
+    }
+}
+
+fn block_on<F: std::future::Future>(fut: F) -> F::Output {
+    let mut fut = std::pin::pin!(fut);
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    loop {
+        if let std::task::Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn future_delegation() {
+    assert_eq!(block_on(Job::from(std::future::ready("now"))), "now");
+    let countdown = || Countdown{ remaining: 3.into(), _pinned: std::marker::PhantomPinned };
+    assert_eq!(block_on(Job::from(countdown())), "done");
+
+    let mut job = std::pin::pin!(Job::from(countdown()));
+    assert!(job.as_mut().try_as_pin_mut_ready().is_none());
+    assert_eq!(job.as_mut().as_pin_mut_countdown().remaining.get(), 3);
+    let _ = std::future::Future::poll(job.as_mut(), &mut std::task::Context::from_waker(std::task::Waker::noop()));
+    assert_eq!(job.as_mut().as_pin_mut_countdown().remaining.get(), 2);
+
+    <Job as AmbiguousIfUnpin<_>>::check();
+    assert_unpin::<Timeout>();
+}
+
+/// An error with a source of its own
+#[derive(Debug)]
+pub struct ConfigError(std::num::ParseIntError);
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "bad config")
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+summum!{
+    #[summum(delegate(Error), transparent_debug)]
+    type AppError = std::io::Error as Io | std::num::ParseIntError as ParseInt | ConfigError;
+}
+
+fn parse_config(text: &str) -> Result<i64, AppError> {
+    if text.is_empty() {
+        Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof))?;
+    }
+    let val = text.parse::<i64>().map_err(ConfigError)?;
+    Ok(val + text.parse::<i64>()?)
+}
+
+#[test]
+fn error_delegation() {
+    use std::error::Error;
+
+    assert_eq!(parse_config("2").unwrap(), 4);
+    let err = parse_config("x").unwrap_err();
+    assert!(err.is_config_error());
+    assert_eq!(err.to_string(), "bad config");
+    assert_eq!(err.source().unwrap().to_string(), "invalid digit found in string");
+    assert_eq!(format!("{err:?}"), "ConfigError(ParseIntError { kind: InvalidDigit })");
+
+    let err = parse_config("").unwrap_err();
+    assert!(err.is_io());
+    assert!(err.source().is_none());
+
+    let boxed: Box<dyn Error> = Box::new(AppError::from("9999999999999999999".parse::<i64>().unwrap_err()));
+    assert_eq!(boxed.to_string(), "number too large to fit in target type");
+    assert!(boxed.downcast_ref::<AppError>().unwrap().is_parse_int());
+}